@@ -1,6 +1,6 @@
 use std::fmt;
 
-use super::{Comment, Identifier, Indent, UnionParent, capitalize_first};
+use super::{Comment, DeprecatedAnnotation, Identifier, Indent, UnionParent, capitalize_first};
 
 #[derive(Debug, Clone)]
 pub struct Enum {
@@ -8,6 +8,7 @@ pub struct Enum {
     pub description: Option<Comment>,
     pub variants: Vec<EnumVariant>,
     pub union_parents: Vec<UnionParent>,
+    pub deprecated: bool,
 }
 
 #[derive(Debug, Clone)]
@@ -15,6 +16,8 @@ pub struct EnumVariant {
     pub name: Identifier,
     pub value: String,
     pub description: Option<Comment>,
+    pub deprecated: bool,
+    pub replacement: Option<String>,
 }
 
 impl fmt::Display for Enum {
@@ -22,6 +25,9 @@ impl fmt::Display for Enum {
         for comment in self.description.iter().flat_map(Comment::lines) {
             writeln!(f, "/// {comment}")?;
         }
+        if self.deprecated {
+            writeln!(f, "{}", DeprecatedAnnotation(None))?;
+        }
         writeln!(f, "enum {name} {{", name = self.name.as_ref())?;
         {
             let indent = Indent(1);
@@ -31,6 +37,13 @@ impl fmt::Display for Enum {
                 for comment in variant.description.iter().flat_map(Comment::lines) {
                     writeln!(f, "{indent}/// {comment}")?;
                 }
+                if variant.deprecated {
+                    writeln!(
+                        f,
+                        "{indent}{}",
+                        DeprecatedAnnotation(variant.replacement.as_deref())
+                    )?;
+                }
                 writeln!(f, "{indent}{variant}{terminator}")?;
             }
             writeln!(f)?;
@@ -91,6 +104,8 @@ mod tests {
                     name: Identifier::try_from("VARIANT_A").unwrap(),
                     value: "value_a".into(),
                     description: None,
+                    deprecated: false,
+                    replacement: None,
                 },
                 EnumVariant {
                     name: Identifier::try_from("VARIANT_B").unwrap(),
@@ -99,9 +114,12 @@ mod tests {
                         Comment::try_from("This is a variant\nwith a multi-line description")
                             .unwrap(),
                     ),
+                    deprecated: false,
+                    replacement: None,
                 },
             ],
             union_parents: vec![],
+            deprecated: false,
         };
         assert_eq!(
             empty.to_string(),
@@ -129,6 +147,8 @@ enum Test1 {
                 name: Identifier::try_from("PAYPAL_SPB").unwrap(),
                 value: "PAYPAL_SPB".into(),
                 description: None,
+                deprecated: false,
+                replacement: None,
             }],
             union_parents: vec![UnionParent::Union {
                 parent: TypeReference {
@@ -137,6 +157,7 @@ enum Test1 {
                 },
                 variant_name: Identifier::try_from("paymentUiType").unwrap(),
             }],
+            deprecated: false,
         };
         assert_eq!(
             enum_entity.to_string(),
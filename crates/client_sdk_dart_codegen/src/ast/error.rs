@@ -0,0 +1,162 @@
+use std::fmt;
+
+use super::{
+    Comment, Identifier, Indent, ObjectField,
+    object::{ConstructorParameter, FromJson},
+};
+
+/// 모든 에러 클래스가 상속하는 기본 클래스 이름
+pub const BASE_ERROR_NAME: &str = "PortOneError";
+
+#[derive(Debug, Clone)]
+pub struct ErrorClass {
+    pub name: Identifier,
+    pub description: Option<Comment>,
+    pub transaction_type: Option<String>,
+    pub fields: Vec<ObjectField>,
+}
+
+impl fmt::Display for ErrorClass {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for comment in self.description.iter().flat_map(Comment::lines) {
+            writeln!(f, "/// {comment}")?;
+        }
+        writeln!(
+            f,
+            "class {name} extends {BASE_ERROR_NAME} {{",
+            name = self.name.as_ref()
+        )?;
+        {
+            let indent = Indent(1);
+            for field in self.fields.iter() {
+                for comment in field.description.iter().flat_map(Comment::lines) {
+                    writeln!(f, "{indent}/// {comment}")?;
+                }
+                writeln!(f, "{indent}final {field};")?;
+            }
+            if !self.fields.is_empty() {
+                writeln!(f)?;
+            }
+            if let Some(transaction_type) = &self.transaction_type {
+                writeln!(f, "{indent}@override")?;
+                writeln!(
+                    f,
+                    "{indent}String? get transactionType => '{transaction_type}';"
+                )?;
+                writeln!(f)?;
+            }
+
+            if self.fields.is_empty() {
+                writeln!(f, "{indent}{name}();", name = self.name.as_ref())?;
+                writeln!(f)?;
+                writeln!(
+                    f,
+                    "{indent}static {name} fromJson(Map<String, dynamic> json) => {name}();",
+                    name = self.name.as_ref()
+                )?;
+            } else {
+                writeln!(f, "{indent}{name}({{", name = self.name.as_ref())?;
+                {
+                    let indent = Indent(2);
+                    for field in self.fields.iter() {
+                        writeln!(f, "{indent}{}", ConstructorParameter(field))?;
+                    }
+                }
+                writeln!(f, "{indent}}});")?;
+                writeln!(f)?;
+                writeln!(
+                    f,
+                    "{indent}static {name} fromJson(Map<String, dynamic> json) => {name}(",
+                    name = self.name.as_ref()
+                )?;
+                {
+                    let indent = Indent(2);
+                    for field in self.fields.iter() {
+                        let from_json = FromJson {
+                            serialized_name: &field.serialized_name,
+                            is_list: field.value_type.is_list,
+                            scalar: &field.value_type.scalar,
+                            is_required: field.value_type.is_required,
+                        };
+                        writeln!(
+                            f,
+                            "{indent}{field_name}: {from_json},",
+                            field_name = field.name.as_ref(),
+                        )?;
+                    }
+                }
+                writeln!(f, "{indent});")?;
+            }
+        }
+        writeln!(f, "}}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ast::{CompositeType, ScalarType};
+
+    use super::*;
+
+    #[test]
+    fn error_class() {
+        let error = ErrorClass {
+            name: Identifier::try_from("PaymentError").unwrap(),
+            description: Some(Comment::try_from("결제 실패 시 발생하는 에러").unwrap()),
+            transaction_type: Some("PAYMENT".to_string()),
+            fields: vec![
+                ObjectField {
+                    name: Identifier::try_from("paymentId").unwrap(),
+                    serialized_name: "paymentId".to_string(),
+                    value_type: CompositeType {
+                        scalar: ScalarType::String,
+                        is_list: false,
+                        is_required: false,
+                    },
+                    description: None,
+                    import_alias: None,
+                    hide_if_empty: false,
+                    deprecated: false,
+                    default_value: None,
+                },
+                ObjectField {
+                    name: Identifier::try_from("message").unwrap(),
+                    serialized_name: "message".to_string(),
+                    value_type: CompositeType {
+                        scalar: ScalarType::String,
+                        is_list: false,
+                        is_required: true,
+                    },
+                    description: Some(Comment::try_from("에러 메시지").unwrap()),
+                    import_alias: None,
+                    hide_if_empty: false,
+                    deprecated: false,
+                    default_value: None,
+                },
+            ],
+        };
+        assert_eq!(
+            error.to_string(),
+            r#"/// 결제 실패 시 발생하는 에러
+class PaymentError extends PortOneError {
+    final String? paymentId;
+    /// 에러 메시지
+    final String message;
+
+    @override
+    String? get transactionType => 'PAYMENT';
+
+    PaymentError({
+        this.paymentId,
+        required this.message,
+    });
+
+    static PaymentError fromJson(Map<String, dynamic> json) => PaymentError(
+        paymentId: json['paymentId'] as String?,
+        message: json['message'] as String,
+    );
+}
+"#
+        );
+    }
+}
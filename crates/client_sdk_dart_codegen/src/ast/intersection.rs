@@ -1,8 +1,9 @@
 use core::fmt;
 
 use super::{
-    Comment, Identifier, Indent, ObjectField, ScalarType, TypeReference, UnionParent,
+    Comment, DeprecatedAnnotation, Identifier, Indent, ObjectField, TypeReference, UnionParent,
     capitalize_first,
+    object::{ConstructorParameter, FromJson, MapEntry},
 };
 
 pub struct Intersection {
@@ -12,6 +13,7 @@ pub struct Intersection {
     pub fields: Vec<ObjectField>,
     pub union_parents: Vec<UnionParent>,
     pub skip_from_json: bool,
+    pub deprecated: bool,
 }
 
 pub struct IntersectionConstituent {
@@ -24,6 +26,9 @@ impl fmt::Display for Intersection {
         for comment in self.description.iter().flat_map(Comment::lines) {
             writeln!(f, "/// {comment}")?;
         }
+        if self.deprecated {
+            writeln!(f, "{}", DeprecatedAnnotation(None))?;
+        }
         writeln!(f, "class {name} {{", name = self.name.as_ref())?;
         {
             let indent = Indent(1);
@@ -41,6 +46,9 @@ impl fmt::Display for Intersection {
                     for comment in field.description.iter().flat_map(Comment::lines) {
                         writeln!(f, "{indent}/// {comment}")?;
                     }
+                    if field.deprecated {
+                        writeln!(f, "{indent}{}", DeprecatedAnnotation(None))?;
+                    }
                     writeln!(f, "{indent}final {field};")?;
                 }
                 writeln!(f)?;
@@ -48,19 +56,7 @@ impl fmt::Display for Intersection {
                 {
                     let indent = Indent(2);
                     for field in self.fields.iter() {
-                        if field.value_type.is_required {
-                            writeln!(
-                                f,
-                                "{indent}required this.{field_name},",
-                                field_name = field.name.as_ref()
-                            )?;
-                        } else {
-                            writeln!(
-                                f,
-                                "{indent}this.{field_name},",
-                                field_name = field.name.as_ref()
-                            )?;
-                        }
+                        writeln!(f, "{indent}{}", ConstructorParameter(field))?;
                     }
                 }
                 writeln!(f, "{indent}}});")?;
@@ -69,27 +65,7 @@ impl fmt::Display for Intersection {
                 {
                     let indent = Indent(2);
                     for field in self.fields.iter() {
-                        let to_json = ToJson {
-                            name: field.name.as_ref(),
-                            is_list: field.value_type.is_list,
-                            scalar: &field.value_type.scalar,
-                            assert_non_null: !field.value_type.is_required,
-                            null_aware_call: false,
-                        };
-                        if to_json.assert_non_null {
-                            writeln!(
-                                f,
-                                "{indent}if ({field_name} != null) '{serialized_name}': {to_json},",
-                                field_name = field.name.as_ref(),
-                                serialized_name = field.serialized_name,
-                            )?;
-                        } else {
-                            writeln!(
-                                f,
-                                "{indent}'{serialized_name}': {to_json},",
-                                serialized_name = field.serialized_name,
-                            )?;
-                        }
+                        writeln!(f, "{indent}{}", MapEntry(field))?;
                     }
                 }
                 writeln!(f, "{indent}}};")?;
@@ -142,109 +118,9 @@ impl fmt::Display for Intersection {
     }
 }
 
-struct ToJson<'a> {
-    name: &'a str,
-    is_list: bool,
-    scalar: &'a ScalarType,
-    assert_non_null: bool,
-    null_aware_call: bool,
-}
-
-impl fmt::Display for ToJson<'_> {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        let name = self.name;
-        let non_null = if self.assert_non_null { "!" } else { "" };
-        match self.scalar {
-            ScalarType::Int
-            | ScalarType::Double
-            | ScalarType::Bool
-            | ScalarType::Object
-            | ScalarType::String => {
-                write!(f, "{name}{non_null}")
-            }
-            ScalarType::TypeReference(_) => {
-                let call = if self.null_aware_call { "?" } else { "" };
-                if self.is_list {
-                    write!(f, "{name}{non_null}{call}.map((e) => e.toJson()).toList()")
-                } else {
-                    write!(f, "{name}{non_null}{call}.toJson()")
-                }
-            }
-        }
-    }
-}
-
-struct FromJson<'a> {
-    serialized_name: &'a str,
-    is_list: bool,
-    scalar: &'a ScalarType,
-    is_required: bool,
-}
-
-impl fmt::Display for FromJson<'_> {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        let key = self.serialized_name;
-        if self.is_list {
-            match self.scalar {
-                ScalarType::TypeReference(type_ref) => {
-                    let type_name = type_ref.name.as_ref();
-                    if self.is_required {
-                        write!(
-                            f,
-                            "(json['{key}'] as List).map((e) => {type_name}.fromJson(e)).toList()"
-                        )
-                    } else {
-                        write!(
-                            f,
-                            "(json['{key}'] as List?)?.map((e) => {type_name}.fromJson(e)).toList()"
-                        )
-                    }
-                }
-                scalar => {
-                    let dart_type = scalar.to_identifier();
-                    if self.is_required {
-                        write!(f, "(json['{key}'] as List).cast<{dart_type}>()")
-                    } else {
-                        write!(f, "(json['{key}'] as List?)?.cast<{dart_type}>()")
-                    }
-                }
-            }
-        } else {
-            match self.scalar {
-                ScalarType::Double => {
-                    if self.is_required {
-                        write!(f, "(json['{key}'] as num).toDouble()")
-                    } else {
-                        write!(f, "(json['{key}'] as num?)?.toDouble()")
-                    }
-                }
-                ScalarType::Object => {
-                    write!(f, "json['{key}']")
-                }
-                ScalarType::TypeReference(type_ref) => {
-                    let type_name = type_ref.name.as_ref();
-                    if self.is_required {
-                        write!(f, "{type_name}.fromJson(json['{key}'])")
-                    } else {
-                        write!(
-                            f,
-                            "json['{key}'] != null ? {type_name}.fromJson(json['{key}']) : null"
-                        )
-                    }
-                }
-                scalar => {
-                    let dart_type = scalar.to_identifier();
-                    let nullable = if self.is_required { "" } else { "?" };
-                    write!(f, "json['{key}'] as {dart_type}{nullable}")
-                }
-            }
-        }
-    }
-}
-
 #[cfg(test)]
 mod tests {
-    use crate::ast::CompositeType;
+    use crate::ast::{CompositeType, ScalarType};
 
     use super::*;
 
@@ -280,6 +156,9 @@ mod tests {
                     },
                     description: Some(Comment::try_from("결제 금액").unwrap()),
                     import_alias: None,
+                    hide_if_empty: false,
+                    deprecated: false,
+                    default_value: None,
                 },
                 ObjectField {
                     name: Identifier::try_from("currency").unwrap(),
@@ -291,6 +170,9 @@ mod tests {
                     },
                     description: Some(Comment::try_from("통화 코드").unwrap()),
                     import_alias: None,
+                    hide_if_empty: false,
+                    deprecated: false,
+                    default_value: None,
                 },
                 ObjectField {
                     name: Identifier::try_from("method").unwrap(),
@@ -305,6 +187,9 @@ mod tests {
                     },
                     description: Some(Comment::try_from("결제 수단").unwrap()),
                     import_alias: None,
+                    hide_if_empty: false,
+                    deprecated: false,
+                    default_value: None,
                 },
                 ObjectField {
                     name: Identifier::try_from("cardInfo").unwrap(),
@@ -319,10 +204,14 @@ mod tests {
                     },
                     description: Some(Comment::try_from("카드 정보").unwrap()),
                     import_alias: None,
+                    hide_if_empty: false,
+                    deprecated: false,
+                    default_value: None,
                 },
             ],
             union_parents: vec![],
             skip_from_json: false,
+            deprecated: false,
         };
         assert_eq!(
             intersection.to_string(),
@@ -0,0 +1,249 @@
+use std::fmt;
+
+use super::{Comment, CompositeType, DeprecatedAnnotation, Identifier, Indent};
+
+/// 스키마의 메소드 목록을 모은 SDK 진입점 abstract class
+#[derive(Debug, Clone)]
+pub struct MethodFacade {
+    pub name: Identifier,
+    pub methods: Vec<Method>,
+}
+
+#[derive(Debug, Clone)]
+pub struct Method {
+    pub name: Identifier,
+    pub description: Option<Comment>,
+    pub input: CompositeType,
+    pub output: Option<CompositeType>,
+    pub callbacks: Vec<Callback>,
+    pub deprecated: bool,
+    pub replacement: Option<String>,
+}
+
+/// 메소드에 named parameter로 전달되는 콜백
+#[derive(Debug, Clone)]
+pub struct Callback {
+    pub name: Identifier,
+    /// 콜백 typedef 이름
+    pub type_name: Identifier,
+    pub description: Option<Comment>,
+    pub parameters: Vec<CallbackParameter>,
+    pub deprecated: bool,
+    pub replacement: Option<String>,
+}
+
+#[derive(Debug, Clone)]
+pub struct CallbackParameter {
+    pub name: Identifier,
+    pub value_type: CompositeType,
+}
+
+impl fmt::Display for MethodFacade {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for callback in self.methods.iter().flat_map(|method| &method.callbacks) {
+            if let Some(ref desc) = callback.description {
+                for line in desc.lines() {
+                    writeln!(f, "/// {line}")?;
+                }
+            }
+            if callback.deprecated {
+                writeln!(
+                    f,
+                    "{}",
+                    DeprecatedAnnotation(callback.replacement.as_deref())
+                )?;
+            }
+            let parameters = callback
+                .parameters
+                .iter()
+                .map(|parameter| format!("{} {}", parameter.value_type, parameter.name.as_ref()))
+                .collect::<Vec<_>>()
+                .join(", ");
+            writeln!(
+                f,
+                "typedef {type_name} = void Function({parameters});",
+                type_name = callback.type_name.as_ref()
+            )?;
+            writeln!(f)?;
+        }
+
+        writeln!(f, "abstract class {name} {{", name = self.name.as_ref())?;
+        {
+            let indent = Indent(1);
+            for method in self.methods.iter() {
+                if let Some(ref desc) = method.description {
+                    for line in desc.lines() {
+                        writeln!(f, "{indent}/// {line}")?;
+                    }
+                }
+                if method.deprecated {
+                    writeln!(
+                        f,
+                        "{indent}{}",
+                        DeprecatedAnnotation(method.replacement.as_deref())
+                    )?;
+                }
+                let output = match &method.output {
+                    Some(output) => output.to_string(),
+                    None => "void".to_string(),
+                };
+                if method.callbacks.is_empty() {
+                    writeln!(
+                        f,
+                        "{indent}Future<{output}> {name}({input} request);",
+                        name = method.name.as_ref(),
+                        input = method.input
+                    )?;
+                } else {
+                    writeln!(
+                        f,
+                        "{indent}Future<{output}> {name}({input} request, {{",
+                        name = method.name.as_ref(),
+                        input = method.input
+                    )?;
+                    {
+                        let indent = Indent(2);
+                        for callback in method.callbacks.iter() {
+                            writeln!(
+                                f,
+                                "{indent}required {type_name} {name},",
+                                type_name = callback.type_name.as_ref(),
+                                name = callback.name.as_ref()
+                            )?;
+                        }
+                    }
+                    writeln!(f, "{indent}}});")?;
+                }
+            }
+        }
+        writeln!(f, "}}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ast::{ScalarType, TypeReference};
+
+    use super::*;
+
+    fn type_reference(name: &str, is_required: bool) -> CompositeType {
+        CompositeType {
+            scalar: ScalarType::TypeReference(TypeReference {
+                name: Identifier::try_from(name).unwrap(),
+                path: "".into(),
+            }),
+            is_list: false,
+            is_required,
+        }
+    }
+
+    #[test]
+    fn method_facade() {
+        let facade = MethodFacade {
+            name: Identifier::try_from("PortOne").unwrap(),
+            methods: vec![
+                Method {
+                    name: Identifier::try_from("requestPayment").unwrap(),
+                    description: Some(Comment::try_from("결제를 요청합니다.").unwrap()),
+                    input: type_reference("PaymentRequest", true),
+                    output: Some(type_reference("PaymentResponse", false)),
+                    callbacks: vec![],
+                    deprecated: false,
+                    replacement: None,
+                },
+                Method {
+                    name: Identifier::try_from("loadPaymentUI").unwrap(),
+                    description: None,
+                    input: type_reference("LoadPaymentUIRequest", true),
+                    output: None,
+                    callbacks: vec![
+                        Callback {
+                            name: Identifier::try_from("onPaymentSuccess").unwrap(),
+                            type_name: Identifier::try_from("LoadPaymentUIOnPaymentSuccess")
+                                .unwrap(),
+                            description: Some(
+                                Comment::try_from("결제 성공 시 호출됩니다.").unwrap(),
+                            ),
+                            parameters: vec![CallbackParameter {
+                                name: Identifier::try_from("response").unwrap(),
+                                value_type: type_reference("PaymentResponse", true),
+                            }],
+                            deprecated: false,
+                            replacement: None,
+                        },
+                        Callback {
+                            name: Identifier::try_from("onPaymentFail").unwrap(),
+                            type_name: Identifier::try_from("LoadPaymentUIOnPaymentFail").unwrap(),
+                            description: None,
+                            parameters: vec![CallbackParameter {
+                                name: Identifier::try_from("error").unwrap(),
+                                value_type: CompositeType {
+                                    scalar: ScalarType::Exception,
+                                    is_list: false,
+                                    is_required: true,
+                                },
+                            }],
+                            deprecated: false,
+                            replacement: None,
+                        },
+                    ],
+                    deprecated: false,
+                    replacement: None,
+                },
+            ],
+        };
+        assert_eq!(
+            facade.to_string(),
+            r#"/// 결제 성공 시 호출됩니다.
+typedef LoadPaymentUIOnPaymentSuccess = void Function(PaymentResponse response);
+
+typedef LoadPaymentUIOnPaymentFail = void Function(Exception error);
+
+abstract class PortOne {
+    /// 결제를 요청합니다.
+    Future<PaymentResponse?> requestPayment(PaymentRequest request);
+    Future<void> loadPaymentUI(LoadPaymentUIRequest request, {
+        required LoadPaymentUIOnPaymentSuccess onPaymentSuccess,
+        required LoadPaymentUIOnPaymentFail onPaymentFail,
+    });
+}
+"#
+        );
+    }
+
+    #[test]
+    fn deprecated_method() {
+        let facade = MethodFacade {
+            name: Identifier::try_from("PortOne").unwrap(),
+            methods: vec![Method {
+                name: Identifier::try_from("requestPaymentLegacy").unwrap(),
+                description: None,
+                input: type_reference("PaymentRequest", true),
+                output: None,
+                callbacks: vec![Callback {
+                    name: Identifier::try_from("onDone").unwrap(),
+                    type_name: Identifier::try_from("RequestPaymentLegacyOnDone").unwrap(),
+                    description: None,
+                    parameters: vec![],
+                    deprecated: true,
+                    replacement: None,
+                }],
+                deprecated: true,
+                replacement: Some("requestPayment".to_string()),
+            }],
+        };
+        assert_eq!(
+            facade.to_string(),
+            r"@Deprecated('This API is deprecated and will be removed in a future version.')
+typedef RequestPaymentLegacyOnDone = void Function();
+
+abstract class PortOne {
+    @Deprecated('Use `requestPayment` instead.')
+    Future<void> requestPaymentLegacy(PaymentRequest request, {
+        required RequestPaymentLegacyOnDone onDone,
+    });
+}
+"
+        );
+    }
+}
@@ -1,7 +1,8 @@
 use std::fmt;
 
 use super::{
-    Comment, CompositeType, Identifier, Indent, ScalarType, UnionParent, capitalize_first,
+    Comment, CompositeType, DeprecatedAnnotation, Identifier, Indent, ScalarType, UnionParent,
+    capitalize_first,
 };
 
 #[derive(Debug, Clone)]
@@ -12,6 +13,7 @@ pub struct Object {
     pub union_parents: Vec<UnionParent>,
     pub is_one_of: bool,
     pub skip_from_json: bool,
+    pub deprecated: bool,
 }
 
 impl fmt::Display for Object {
@@ -19,6 +21,9 @@ impl fmt::Display for Object {
         for comment in self.description.iter().flat_map(Comment::lines) {
             writeln!(f, "/// {comment}")?;
         }
+        if self.deprecated {
+            writeln!(f, "{}", DeprecatedAnnotation(None))?;
+        }
         if self.is_one_of {
             // Sealed class pattern
             writeln!(f, "sealed class {name} {{", name = self.name.as_ref())?;
@@ -69,6 +74,9 @@ impl fmt::Display for Object {
                 for comment in field.description.iter().flat_map(Comment::lines) {
                     writeln!(f, "/// {comment}")?;
                 }
+                if field.deprecated {
+                    writeln!(f, "{}", DeprecatedAnnotation(None))?;
+                }
                 writeln!(
                     f,
                     "class {subclass_name} extends {name} {{",
@@ -134,6 +142,9 @@ impl fmt::Display for Object {
                         for comment in field.description.iter().flat_map(Comment::lines) {
                             writeln!(f, "{indent}/// {comment}")?;
                         }
+                        if field.deprecated {
+                            writeln!(f, "{indent}{}", DeprecatedAnnotation(None))?;
+                        }
                         writeln!(f, "{indent}final {field};")?;
                     }
                     writeln!(f)?;
@@ -141,19 +152,7 @@ impl fmt::Display for Object {
                     {
                         let indent = Indent(2);
                         for field in self.fields.iter() {
-                            if field.value_type.is_required {
-                                writeln!(
-                                    f,
-                                    "{indent}required this.{field_name},",
-                                    field_name = field.name.as_ref()
-                                )?;
-                            } else {
-                                writeln!(
-                                    f,
-                                    "{indent}this.{field_name},",
-                                    field_name = field.name.as_ref()
-                                )?;
-                            }
+                            writeln!(f, "{indent}{}", ConstructorParameter(field))?;
                         }
                     }
                     writeln!(f, "{indent}}});")?;
@@ -162,27 +161,7 @@ impl fmt::Display for Object {
                     {
                         let indent = Indent(2);
                         for field in self.fields.iter() {
-                            let to_json = ToJson {
-                                name: field.name.as_ref(),
-                                is_list: field.value_type.is_list,
-                                scalar: &field.value_type.scalar,
-                                assert_non_null: !field.value_type.is_required,
-                                null_aware_call: false,
-                            };
-                            if to_json.assert_non_null {
-                                writeln!(
-                                    f,
-                                    "{indent}if ({field_name} != null) '{serialized_field_name}': {to_json},",
-                                    field_name = field.name.as_ref(),
-                                    serialized_field_name = field.serialized_name,
-                                )?;
-                            } else {
-                                writeln!(
-                                    f,
-                                    "{indent}'{serialized_field_name}': {to_json},",
-                                    serialized_field_name = field.serialized_name,
-                                )?;
-                            }
+                            writeln!(f, "{indent}{}", MapEntry(field))?;
                         }
                     }
                     writeln!(f, "{indent}}};")?;
@@ -243,6 +222,65 @@ pub struct ObjectField {
     pub value_type: CompositeType,
     pub description: Option<Comment>,
     pub import_alias: Option<String>,
+    /// 값이 비어있을 때 직렬화 결과에서 제외할지 여부
+    pub hide_if_empty: bool,
+    pub deprecated: bool,
+    /// 생성자 인자의 기본값으로 사용할 식
+    pub default_value: Option<String>,
+}
+
+/// 생성자의 named parameter 하나 (지정된 기본값이 있으면 `required`를 붙이지 않음)
+pub(super) struct ConstructorParameter<'a>(pub &'a ObjectField);
+
+impl fmt::Display for ConstructorParameter<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let field = self.0;
+        let name = field.name.as_ref();
+        match (&field.default_value, field.value_type.is_required) {
+            (Some(default_value), _) => write!(f, "this.{name} = {default_value},"),
+            (None, true) => write!(f, "required this.{name},"),
+            (None, false) => write!(f, "this.{name},"),
+        }
+    }
+}
+
+/// `toJson`의 맵 리터럴에서 필드 하나에 해당하는 항목
+pub(super) struct MapEntry<'a>(pub &'a ObjectField);
+
+impl fmt::Display for MapEntry<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let field = self.0;
+        let name = field.name.as_ref();
+        let serialized_name = &field.serialized_name;
+        let is_required = field.value_type.is_required;
+        let to_json = ToJson {
+            name,
+            is_list: field.value_type.is_list,
+            scalar: &field.value_type.scalar,
+            assert_non_null: !is_required,
+            null_aware_call: false,
+        };
+        if field.hide_if_empty {
+            let (nullable, fallback) = if is_required {
+                ("", "")
+            } else {
+                ("?", " ?? false")
+            };
+            let value = if field.value_type.is_list {
+                ""
+            } else {
+                ".toJson()"
+            };
+            write!(
+                f,
+                "if ({name}{nullable}{value}.isNotEmpty{fallback}) '{serialized_name}': {to_json},"
+            )
+        } else if is_required {
+            write!(f, "'{serialized_name}': {to_json},")
+        } else {
+            write!(f, "if ({name} != null) '{serialized_name}': {to_json},")
+        }
+    }
 }
 
 struct ToJson<'a> {
@@ -257,61 +295,55 @@ impl fmt::Display for ToJson<'_> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let name = self.name;
         let non_null = if self.assert_non_null { "!" } else { "" };
-        match self.scalar {
-            ScalarType::Int
-            | ScalarType::Double
-            | ScalarType::Bool
-            | ScalarType::Object
-            | ScalarType::String => {
-                write!(f, "{name}{non_null}")
-            }
-            ScalarType::TypeReference(_) => {
-                let call = if self.null_aware_call { "?" } else { "" };
-                if self.is_list {
-                    write!(f, "{name}{non_null}{call}.map((e) => e.toJson()).toList()")
-                } else {
-                    write!(f, "{name}{non_null}{call}.toJson()")
-                }
-            }
+        let call = if self.null_aware_call { "?" } else { "" };
+        let conversion = if self.is_list {
+            to_json_conversion(self.scalar, "e")
+                .map(|e| format!("{name}{non_null}{call}.map((e) => {e}).toList()"))
+        } else {
+            to_json_conversion(self.scalar, &format!("{name}{non_null}{call}"))
+        };
+        match conversion {
+            Some(conversion) => write!(f, "{conversion}"),
+            None => write!(f, "{name}{non_null}"),
         }
     }
 }
 
-struct FromJson<'a> {
-    serialized_name: &'a str,
-    is_list: bool,
-    scalar: &'a ScalarType,
-    is_required: bool,
+/// 값을 JSON으로 직렬화할 수 있는 값으로 변환하는 식 (변환이 필요 없으면 `None`)
+pub(super) fn to_json_conversion(scalar: &ScalarType, value: &str) -> Option<String> {
+    match scalar {
+        ScalarType::Int
+        | ScalarType::Double
+        | ScalarType::Bool
+        | ScalarType::Object
+        | ScalarType::String
+        | ScalarType::Exception => None,
+        ScalarType::TypeReference(_) => Some(format!("{value}.toJson()")),
+        ScalarType::Map(values) => to_json_conversion(values, "v")
+            .map(|v| format!("{value}.map((k, v) => MapEntry(k, {v}))")),
+        ScalarType::List(items) => {
+            to_json_conversion(items, "e").map(|e| format!("{value}.map((e) => {e}).toList()"))
+        }
+    }
+}
+
+pub(super) struct FromJson<'a> {
+    pub(super) serialized_name: &'a str,
+    pub(super) is_list: bool,
+    pub(super) scalar: &'a ScalarType,
+    pub(super) is_required: bool,
 }
 
 impl fmt::Display for FromJson<'_> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let key = self.serialized_name;
         if self.is_list {
-            match self.scalar {
-                ScalarType::TypeReference(type_ref) => {
-                    let type_name = type_ref.name.as_ref();
-                    if self.is_required {
-                        write!(
-                            f,
-                            "(json['{key}'] as List).map((e) => {type_name}.fromJson(e)).toList()"
-                        )
-                    } else {
-                        write!(
-                            f,
-                            "(json['{key}'] as List?)?.map((e) => {type_name}.fromJson(e)).toList()"
-                        )
-                    }
-                }
-                scalar => {
-                    let dart_type = scalar.to_identifier();
-                    if self.is_required {
-                        write!(f, "(json['{key}'] as List).cast<{dart_type}>()")
-                    } else {
-                        write!(f, "(json['{key}'] as List?)?.cast<{dart_type}>()")
-                    }
-                }
-            }
+            let list = if self.is_required {
+                format!("(json['{key}'] as List)")
+            } else {
+                format!("(json['{key}'] as List?)?")
+            };
+            write!(f, "{}", list_from_json(&list, self.scalar))
         } else {
             match self.scalar {
                 ScalarType::Double => {
@@ -335,6 +367,14 @@ impl fmt::Display for FromJson<'_> {
                         )
                     }
                 }
+                ScalarType::Map(values) => {
+                    let map = if self.is_required {
+                        format!("(json['{key}'] as Map<String, dynamic>)")
+                    } else {
+                        format!("(json['{key}'] as Map<String, dynamic>?)?")
+                    };
+                    write!(f, "{}", map_from_json(&map, values))
+                }
                 scalar => {
                     // Int, Bool, String
                     let dart_type = scalar.to_identifier();
@@ -346,6 +386,40 @@ impl fmt::Display for FromJson<'_> {
     }
 }
 
+fn list_from_json(list: &str, items: &ScalarType) -> String {
+    match from_json_conversion(items, "e") {
+        Some(e) => format!("{list}.map((e) => {e}).toList()"),
+        None => format!("{list}.cast<{}>()", items.to_identifier()),
+    }
+}
+
+fn map_from_json(map: &str, values: &ScalarType) -> String {
+    match from_json_conversion(values, "v") {
+        Some(v) => format!("{map}.map((k, v) => MapEntry(k, {v}))"),
+        None => format!("{map}.cast<String, {}>()", values.to_identifier()),
+    }
+}
+
+/// 배열의 항목이나 맵의 값을 JSON에서 변환하는 식 (타입 캐스팅으로 충분하면 `None`)
+fn from_json_conversion(scalar: &ScalarType, value: &str) -> Option<String> {
+    match scalar {
+        ScalarType::Int
+        | ScalarType::Bool
+        | ScalarType::Object
+        | ScalarType::String
+        | ScalarType::Exception => None,
+        ScalarType::Double => Some(format!("({value} as num).toDouble()")),
+        ScalarType::TypeReference(type_ref) => {
+            Some(format!("{}.fromJson({value})", type_ref.name.as_ref()))
+        }
+        ScalarType::Map(values) => Some(map_from_json(
+            &format!("({value} as Map<String, dynamic>)"),
+            values,
+        )),
+        ScalarType::List(items) => Some(list_from_json(&format!("({value} as List)"), items)),
+    }
+}
+
 impl fmt::Display for ObjectField {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let nullable = if self.value_type.is_required { "" } else { "?" };
@@ -382,6 +456,7 @@ mod tests {
                 variant_name: Identifier::try_from("test").unwrap(),
             }],
             skip_from_json: false,
+            deprecated: false,
         };
         assert_eq!(
             object.to_string(),
@@ -415,6 +490,9 @@ class Test {
                     },
                     description: None,
                     import_alias: None,
+                    hide_if_empty: false,
+                    deprecated: false,
+                    default_value: None,
                 },
                 ObjectField {
                     name: Identifier::try_from("addressLine1").unwrap(),
@@ -426,6 +504,9 @@ class Test {
                     },
                     description: Some(Comment::try_from("**일반주소**").unwrap()),
                     import_alias: None,
+                    hide_if_empty: false,
+                    deprecated: false,
+                    default_value: None,
                 },
                 ObjectField {
                     name: Identifier::try_from("addressLine2").unwrap(),
@@ -437,6 +518,9 @@ class Test {
                     },
                     description: Some(Comment::try_from("**상세주소**").unwrap()),
                     import_alias: None,
+                    hide_if_empty: false,
+                    deprecated: false,
+                    default_value: None,
                 },
                 ObjectField {
                     name: Identifier::try_from("city").unwrap(),
@@ -448,6 +532,9 @@ class Test {
                     },
                     description: Some(Comment::try_from("**도시**").unwrap()),
                     import_alias: None,
+                    hide_if_empty: false,
+                    deprecated: false,
+                    default_value: None,
                 },
                 ObjectField {
                     name: Identifier::try_from("province").unwrap(),
@@ -459,11 +546,15 @@ class Test {
                     },
                     description: Some(Comment::try_from("**주, 도, 시**").unwrap()),
                     import_alias: None,
+                    hide_if_empty: false,
+                    deprecated: false,
+                    default_value: None,
                 },
             ],
             is_one_of: false,
             union_parents: vec![],
             skip_from_json: false,
+            deprecated: false,
         };
         assert_eq!(
             object.to_string(),
@@ -526,6 +617,9 @@ class Address {
                     },
                     description: None,
                     import_alias: Some("_offer_period_range".to_string()),
+                    hide_if_empty: false,
+                    deprecated: false,
+                    default_value: None,
                 },
                 ObjectField {
                     name: Identifier::try_from("unit").unwrap(),
@@ -540,11 +634,15 @@ class Address {
                     },
                     description: None,
                     import_alias: None,
+                    hide_if_empty: false,
+                    deprecated: false,
+                    default_value: None,
                 },
             ],
             is_one_of: true,
             union_parents: vec![],
             skip_from_json: true,
+            deprecated: false,
         };
         assert_eq!(
             object.to_string(),
@@ -588,6 +686,9 @@ class OfferPeriodUnit extends OfferPeriod {
                             .unwrap(),
                     ),
                     import_alias: None,
+                    hide_if_empty: false,
+                    deprecated: false,
+                    default_value: None,
                 },
                 ObjectField {
                     name: Identifier::try_from("availableMonthList").unwrap(),
@@ -602,11 +703,15 @@ class OfferPeriodUnit extends OfferPeriod {
                             .unwrap(),
                     ),
                     import_alias: None,
+                    hide_if_empty: false,
+                    deprecated: false,
+                    default_value: None,
                 },
             ],
             is_one_of: true,
             union_parents: vec![],
             skip_from_json: false,
+            deprecated: false,
         };
         assert_eq!(
             object.to_string(),
@@ -639,6 +744,174 @@ class MonthOptionAvailableMonthList extends MonthOption {
     @override
     Map<String, dynamic> toJson() => {'availableMonthList': availableMonthList};
 }
+"
+        );
+    }
+
+    #[test]
+    fn object_with_hide_if_empty_fields() {
+        let field =
+            |name: &str, scalar: ScalarType, is_list: bool, is_required: bool| ObjectField {
+                name: Identifier::try_from(name).unwrap(),
+                serialized_name: name.to_string(),
+                value_type: CompositeType {
+                    scalar,
+                    is_list,
+                    is_required,
+                },
+                description: None,
+                import_alias: None,
+                hide_if_empty: true,
+                deprecated: false,
+                default_value: None,
+            };
+        let bypass = || {
+            ScalarType::TypeReference(TypeReference {
+                name: Identifier::try_from("Bypass").unwrap(),
+                path: "".into(),
+            })
+        };
+        let object = Object {
+            name: Identifier::try_from("Request").unwrap(),
+            description: None,
+            fields: vec![
+                field("products", ScalarType::String, true, true),
+                field("tags", ScalarType::String, true, false),
+                field("bypass", bypass(), false, true),
+                field("extra", bypass(), false, false),
+            ],
+            is_one_of: false,
+            union_parents: vec![],
+            skip_from_json: true,
+            deprecated: false,
+        };
+        assert_eq!(
+            object.to_string(),
+            r"class Request {
+    final List<String> products;
+    final List<String>? tags;
+    final Bypass bypass;
+    final Bypass? extra;
+
+    Request({
+        required this.products,
+        this.tags,
+        required this.bypass,
+        this.extra,
+    });
+
+    Map<String, dynamic> toJson() => {
+        if (products.isNotEmpty) 'products': products,
+        if (tags?.isNotEmpty ?? false) 'tags': tags!,
+        if (bypass.toJson().isNotEmpty) 'bypass': bypass.toJson(),
+        if (extra?.toJson().isNotEmpty ?? false) 'extra': extra!.toJson(),
+    };
+}
+"
+        );
+    }
+
+    #[test]
+    fn deprecated_object() {
+        let object = Object {
+            name: Identifier::try_from("Customer").unwrap(),
+            description: None,
+            fields: vec![ObjectField {
+                name: Identifier::try_from("birthYear").unwrap(),
+                serialized_name: "birthYear".to_string(),
+                value_type: CompositeType {
+                    scalar: ScalarType::String,
+                    is_list: false,
+                    is_required: false,
+                },
+                description: None,
+                import_alias: None,
+                hide_if_empty: false,
+                deprecated: true,
+                default_value: None,
+            }],
+            is_one_of: false,
+            union_parents: vec![],
+            skip_from_json: true,
+            deprecated: true,
+        };
+        assert_eq!(
+            object.to_string(),
+            r"@Deprecated('This API is deprecated and will be removed in a future version.')
+class Customer {
+    @Deprecated('This API is deprecated and will be removed in a future version.')
+    final String? birthYear;
+
+    Customer({
+        this.birthYear,
+    });
+
+    Map<String, dynamic> toJson() => {
+        if (birthYear != null) 'birthYear': birthYear!,
+    };
+}
+"
+        );
+    }
+
+    #[test]
+    fn object_with_map_fields() {
+        let field = |name: &str, values: ScalarType, is_required: bool| ObjectField {
+            name: Identifier::try_from(name).unwrap(),
+            serialized_name: name.to_string(),
+            value_type: CompositeType {
+                scalar: ScalarType::Map(Box::new(values)),
+                is_list: false,
+                is_required,
+            },
+            description: None,
+            import_alias: None,
+            hide_if_empty: false,
+            deprecated: false,
+            default_value: None,
+        };
+        let amount = ScalarType::TypeReference(TypeReference {
+            name: Identifier::try_from("Amount").unwrap(),
+            path: "".into(),
+        });
+        let object = Object {
+            name: Identifier::try_from("Result").unwrap(),
+            description: None,
+            fields: vec![
+                field("amounts", amount, true),
+                field("rates", ScalarType::Double, false),
+                field("metadata", ScalarType::String, true),
+            ],
+            is_one_of: false,
+            union_parents: vec![],
+            skip_from_json: false,
+            deprecated: false,
+        };
+        assert_eq!(
+            object.to_string(),
+            r"class Result {
+    final Map<String, Amount> amounts;
+    final Map<String, double>? rates;
+    final Map<String, String> metadata;
+
+    Result({
+        required this.amounts,
+        this.rates,
+        required this.metadata,
+    });
+
+    Map<String, dynamic> toJson() => {
+        'amounts': amounts.map((k, v) => MapEntry(k, v.toJson())),
+        if (rates != null) 'rates': rates!,
+        'metadata': metadata,
+    };
+
+    static Result fromJson(Map<String, dynamic> json) => Result(
+        amounts: (json['amounts'] as Map<String, dynamic>).map((k, v) => MapEntry(k, Amount.fromJson(v))),
+        rates: (json['rates'] as Map<String, dynamic>?)?.map((k, v) => MapEntry(k, (v as num).toDouble())),
+        metadata: (json['metadata'] as Map<String, dynamic>).cast<String, String>(),
+    );
+}
 "
         );
     }
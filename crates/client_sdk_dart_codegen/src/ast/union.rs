@@ -2,7 +2,10 @@ use std::fmt;
 
 use crate::ast::Indent;
 
-use super::{Comment, Identifier, TypeReference, capitalize_first};
+use super::{
+    Comment, DeprecatedAnnotation, Identifier, ScalarType, TypeReference, capitalize_first,
+    object::to_json_conversion, string_literal,
+};
 
 #[derive(Debug, Clone)]
 pub enum UnionParent {
@@ -17,20 +20,34 @@ pub struct Union {
     pub name: Identifier,
     pub description: Option<Comment>,
     pub variants: Vec<UnionVariant>,
+    pub deprecated: bool,
 }
 
 #[derive(Debug, Clone)]
 pub struct UnionVariant {
     pub name: Identifier,
-    pub type_name: TypeReference,
+    pub value_type: UnionVariantType,
     pub description: Option<Comment>,
 }
 
+#[derive(Debug, Clone)]
+pub enum UnionVariantType {
+    /// 리소스로 생성된 타입
+    TypeReference(TypeReference),
+    /// 원시 타입의 값
+    Scalar(ScalarType),
+    /// 값이 고정된 문자열 리터럴
+    Literal(String),
+}
+
 impl fmt::Display for Union {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         for comment in self.description.iter().flat_map(Comment::lines) {
             writeln!(f, "/// {comment}")?;
         }
+        if self.deprecated {
+            writeln!(f, "{}", DeprecatedAnnotation(None))?;
+        }
         writeln!(f, "sealed class {name} {{", name = self.name.as_ref())?;
         {
             let indent = Indent(1);
@@ -56,14 +73,32 @@ impl fmt::Display for Union {
             )?;
             {
                 let indent = Indent(1);
-                writeln!(
-                    f,
-                    "{indent}final {variant_type} value;",
-                    variant_type = variant.type_name.name.as_ref(),
-                )?;
-                writeln!(f, "{indent}{subclass_name}(this.value);")?;
+                let to_json = match &variant.value_type {
+                    UnionVariantType::TypeReference(type_name) => {
+                        writeln!(
+                            f,
+                            "{indent}final {variant_type} value;",
+                            variant_type = type_name.name.as_ref(),
+                        )?;
+                        writeln!(f, "{indent}{subclass_name}(this.value);")?;
+                        "value.toJson()".to_string()
+                    }
+                    UnionVariantType::Scalar(scalar) => {
+                        writeln!(
+                            f,
+                            "{indent}final {variant_type} value;",
+                            variant_type = scalar.to_identifier(),
+                        )?;
+                        writeln!(f, "{indent}{subclass_name}(this.value);")?;
+                        to_json_conversion(scalar, "value").unwrap_or("value".to_string())
+                    }
+                    UnionVariantType::Literal(value) => {
+                        writeln!(f, "{indent}{subclass_name}();")?;
+                        string_literal(value)
+                    }
+                };
                 writeln!(f, "{indent}@override")?;
-                writeln!(f, "{indent}dynamic toJson() => value.toJson();")?;
+                writeln!(f, "{indent}dynamic toJson() => {to_json};")?;
             }
             writeln!(f, "}}")?;
         }
@@ -83,21 +118,22 @@ mod tests {
             variants: vec![
                 UnionVariant {
                     name: Identifier::try_from("paymentUiType").unwrap(),
-                    type_name: TypeReference {
+                    value_type: UnionVariantType::TypeReference(TypeReference {
                         name: Identifier::try_from("PaymentUIType").unwrap(),
                         path: "".into(),
-                    },
+                    }),
                     description: None,
                 },
                 UnionVariant {
                     name: Identifier::try_from("issueBillingKeyUiType").unwrap(),
-                    type_name: TypeReference {
+                    value_type: UnionVariantType::TypeReference(TypeReference {
                         name: Identifier::try_from("IssueBillingKeyUIType").unwrap(),
                         path: "".into(),
-                    },
+                    }),
                     description: None,
                 },
             ],
+            deprecated: false,
         };
         assert_eq!(
             union.to_string(),
@@ -0,0 +1,299 @@
+use std::fmt::Write;
+
+use client_sdk_schema::{Change, ChangeKind, Element, Schema, SchemaDiff};
+
+use crate::Generator;
+
+/// 변경 위치에서 항목 이름을 만들 때 건너뛰는 경로 구분자
+const PATH_KEYWORDS: [&str; 7] = [
+    "properties",
+    "variants",
+    "items",
+    "types",
+    "input",
+    "output",
+    "callbacks",
+];
+
+type ChangeFilter = fn(&Change) -> bool;
+
+/// 타겟 SDK에서 생성되는 항목만 비교합니다.
+///
+/// 모바일 SDK에서는 생성되지 않는 메소드와 리소스의 변경 사항을 제외합니다.
+pub fn diff(generator: Generator, mut old: Schema, mut new: Schema) -> SchemaDiff {
+    if !matches!(generator, Generator::TypeScript) {
+        old.retain_mobile();
+        new.retain_mobile();
+    }
+    old.diff(&new)
+}
+
+/// 한 타겟 SDK의 변경 사항을 Markdown 섹션으로 작성합니다.
+pub fn write_section(out: &mut String, generator: Generator, diff: &SchemaDiff) {
+    let title = match generator {
+        Generator::TypeScript => "TypeScript",
+        Generator::Dart => "Dart",
+        Generator::Kotlin => "Kotlin",
+        Generator::Swift => "Swift",
+    };
+    writeln!(out, "## {title}\n").unwrap();
+
+    let sections: [(&str, ChangeFilter); 4] = [
+        ("새 메소드", |change| {
+            change.kind == ChangeKind::Added(Element::Method)
+        }),
+        ("새 요청 필드", |change| {
+            change.kind == ChangeKind::Added(Element::Property) && change.direction.input
+        }),
+        ("새 enum variant", |change| {
+            change.kind == ChangeKind::Added(Element::Variant)
+        }),
+        ("Deprecated", |change| change.kind == ChangeKind::Deprecated),
+    ];
+    let mut is_empty = true;
+    for (heading, filter) in &sections {
+        let changes: Vec<_> = diff
+            .changes
+            .iter()
+            .filter(|change| filter(change))
+            .collect();
+        if changes.is_empty() {
+            continue;
+        }
+        is_empty = false;
+        writeln!(out, "### {heading}\n").unwrap();
+        for change in changes {
+            write_item(out, change, change.description.as_deref());
+        }
+        writeln!(out).unwrap();
+    }
+    let breaking_changes: Vec<_> = diff
+        .changes
+        .iter()
+        .filter(|change| change.breaking)
+        .collect();
+    if !breaking_changes.is_empty() {
+        is_empty = false;
+        writeln!(out, "### 호환되지 않는 변경\n").unwrap();
+        for change in breaking_changes {
+            // 호환되지 않는 변경은 설명보다 무엇이 바뀌었는지를 표시
+            write_item(out, change, Some(&change.kind.to_string()));
+        }
+        writeln!(out).unwrap();
+    }
+    if is_empty {
+        writeln!(out, "변경 사항이 없습니다.\n").unwrap();
+    }
+}
+
+fn write_item(out: &mut String, change: &Change, summary: Option<&str>) {
+    write!(out, "- `{}`", item_name(&change.path)).unwrap();
+    // 여러 줄로 된 설명은 첫 줄만 사용
+    if let Some(summary) = summary.and_then(|summary| summary.lines().next()) {
+        write!(out, ": {summary}").unwrap();
+    }
+    writeln!(out).unwrap();
+}
+
+/// `#/resources/entity/Bank/variants/KAKAO`와 같은 위치를 `Bank.KAKAO`로 변환합니다.
+fn item_name(path: &str) -> String {
+    let segments: Vec<_> = path.trim_start_matches("#/").split('/').skip(1).collect();
+    // 첫 segment는 구분자와 이름이 같아도 항목 이름 (예: `input` 메소드, `types` 디렉토리)
+    let owner_end = segments
+        .iter()
+        .skip(1)
+        .position(|segment| PATH_KEYWORDS.contains(segment))
+        .map_or(segments.len(), |position| position + 1);
+    segments[owner_end.saturating_sub(1)..owner_end]
+        .iter()
+        .chain(
+            segments[owner_end..]
+                .iter()
+                .filter(|segment| !PATH_KEYWORDS.contains(segment)),
+        )
+        .copied()
+        .collect::<Vec<_>>()
+        .join(".")
+}
+
+#[cfg(test)]
+mod tests {
+    use client_sdk_schema::Direction;
+
+    use super::*;
+
+    fn change(path: &str, kind: ChangeKind, breaking: bool, description: Option<&str>) -> Change {
+        Change {
+            path: path.to_string(),
+            kind,
+            breaking,
+            direction: Direction {
+                input: true,
+                output: false,
+            },
+            description: description.map(str::to_string),
+        }
+    }
+
+    #[test]
+    fn converts_paths_to_item_names() {
+        assert_eq!(item_name("#/resources/entity/Bank"), "Bank");
+        assert_eq!(
+            item_name("#/resources/entity/Bank/variants/KAKAO"),
+            "Bank.KAKAO"
+        );
+        assert_eq!(
+            item_name("#/resources/request/PaymentRequest/properties/customer/properties/name"),
+            "PaymentRequest.customer.name"
+        );
+        assert_eq!(
+            item_name("#/methods/requestPayment/callbacks/onSuccess/input/response"),
+            "requestPayment.onSuccess.response"
+        );
+        // 구분자와 이름이 같은 메소드와 디렉토리
+        assert_eq!(item_name("#/methods/input/input"), "input");
+        assert_eq!(
+            item_name("#/resources/types/Bank/properties/code"),
+            "Bank.code"
+        );
+        assert_eq!(item_name("#/methods"), "");
+    }
+
+    #[test]
+    fn writes_section_grouped_by_change() {
+        let diff = SchemaDiff {
+            changes: vec![
+                change(
+                    "#/methods/requestIdentityVerification",
+                    ChangeKind::Added(Element::Method),
+                    false,
+                    Some("본인인증을 요청합니다.\n\n자세한 설명"),
+                ),
+                change(
+                    "#/resources/request/PaymentRequest/properties/locale",
+                    ChangeKind::Added(Element::Property),
+                    false,
+                    None,
+                ),
+                change(
+                    "#/resources/entity/Bank/variants/KAKAO",
+                    ChangeKind::Removed(Element::Variant),
+                    true,
+                    Some("카카오뱅크"),
+                ),
+            ],
+        };
+        let mut out = String::new();
+        write_section(&mut out, Generator::Kotlin, &diff);
+        assert_eq!(
+            out,
+            "## Kotlin
+
+### 새 메소드
+
+- `requestIdentityVerification`: 본인인증을 요청합니다.
+
+### 새 요청 필드
+
+- `PaymentRequest.locale`
+
+### 호환되지 않는 변경
+
+- `Bank.KAKAO`: enum variant removed
+
+"
+        );
+
+        let mut out = String::new();
+        write_section(&mut out, Generator::Swift, &SchemaDiff::default());
+        assert_eq!(out, "## Swift\n\n변경 사항이 없습니다.\n\n");
+    }
+
+    #[test]
+    fn excludes_changes_not_generated_in_mobile_sdks() {
+        let old: Schema = serde_yaml_ng::from_str(
+            r##"
+flags: {}
+resources:
+  entity:
+    Bank:
+      type: enum
+      variants:
+        KB: {}
+  misc:
+    Internal:
+      type: enum
+      variants:
+        A: {}
+methods: {}
+"##,
+        )
+        .unwrap();
+        let new: Schema = serde_yaml_ng::from_str(
+            r##"
+flags: {}
+resources:
+  entity:
+    Bank:
+      type: enum
+      variants:
+        KB: {}
+        KAKAO: {}
+  misc:
+    Internal:
+      type: enum
+      variants:
+        A: {}
+        B: {}
+methods:
+  loadPaymentUI:
+    input:
+      type: object
+      properties: {}
+"##,
+        )
+        .unwrap();
+
+        let mut out = String::new();
+        let kotlin = diff(Generator::Kotlin, old.clone(), new.clone());
+        write_section(&mut out, Generator::Kotlin, &kotlin);
+        assert_eq!(
+            out,
+            "## Kotlin
+
+### 새 enum variant
+
+- `Bank.KAKAO`
+
+### 호환되지 않는 변경
+
+- `Bank.KAKAO`: enum variant added
+
+"
+        );
+
+        let mut out = String::new();
+        let typescript = diff(Generator::TypeScript, old, new);
+        write_section(&mut out, Generator::TypeScript, &typescript);
+        assert_eq!(
+            out,
+            "## TypeScript
+
+### 새 메소드
+
+- `loadPaymentUI`
+
+### 새 enum variant
+
+- `Bank.KAKAO`
+- `Internal.B`
+
+### 호환되지 않는 변경
+
+- `Bank.KAKAO`: enum variant added
+- `Internal.B`: enum variant added
+
+"
+        );
+    }
+}
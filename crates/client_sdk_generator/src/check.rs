@@ -0,0 +1,230 @@
+use std::{
+    collections::BTreeMap,
+    fmt, fs,
+    path::{Path, PathBuf},
+};
+
+/// `write_generated_file`이 모든 생성 파일 앞에 붙이는 헤더
+const GENERATED_HEADER: &str = "// This file is automatically generated by the SDK generator.";
+
+#[derive(Debug, Default)]
+pub struct CheckReport {
+    pub added: Vec<PathBuf>,
+    pub removed: Vec<PathBuf>,
+    pub changed: Vec<PathBuf>,
+}
+
+impl CheckReport {
+    pub fn is_up_to_date(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.changed.is_empty()
+    }
+}
+
+impl fmt::Display for CheckReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (label, paths) in [
+            ("added", &self.added),
+            ("removed", &self.removed),
+            ("changed", &self.changed),
+        ] {
+            for path in paths {
+                writeln!(f, "{label}: {}", path.display())?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// 임시 디렉토리에 코드를 생성한 뒤 `out_dir`의 파일들과 비교합니다.
+///
+/// 포매터가 `out_dir`과 같은 프로젝트 설정(`.editorconfig`, `.swiftformat` 등)을 읽도록
+/// 임시 디렉토리는 `out_dir` 옆에 만듭니다.
+pub fn check(out_dir: &Path, generate: impl FnOnce(&PathBuf)) -> CheckReport {
+    let temp_dir = scratch_dir(out_dir);
+    if temp_dir.exists() {
+        fs::remove_dir_all(&temp_dir).unwrap();
+    }
+    fs::create_dir_all(&temp_dir).unwrap();
+    generate(&temp_dir);
+
+    let generated = collect_files(&temp_dir);
+    let existing = collect_files(out_dir);
+    fs::remove_dir_all(&temp_dir).unwrap();
+
+    compare(&generated, &existing)
+}
+
+fn scratch_dir(out_dir: &Path) -> PathBuf {
+    let out_dir = out_dir
+        .canonicalize()
+        .or_else(|_| std::path::absolute(out_dir))
+        .unwrap();
+    let name = out_dir
+        .file_name()
+        .map_or_else(|| "out".into(), |name| name.to_string_lossy());
+    out_dir.with_file_name(format!(".{name}-check-{}", std::process::id()))
+}
+
+fn compare(
+    generated: &BTreeMap<PathBuf, Vec<u8>>,
+    existing: &BTreeMap<PathBuf, Vec<u8>>,
+) -> CheckReport {
+    let mut report = CheckReport::default();
+    for (path, content) in generated {
+        match existing.get(path) {
+            None => report.added.push(path.clone()),
+            Some(existing_content) if existing_content != content => {
+                report.changed.push(path.clone())
+            }
+            Some(_) => {}
+        }
+    }
+    for (path, content) in existing {
+        // 직접 작성한 파일은 생성 대상이 아니므로 제외
+        if !generated.contains_key(path) && content.starts_with(GENERATED_HEADER.as_bytes()) {
+            report.removed.push(path.clone());
+        }
+    }
+    report
+}
+
+fn collect_files(root: &Path) -> BTreeMap<PathBuf, Vec<u8>> {
+    fn visit(root: &Path, dir: &Path, files: &mut BTreeMap<PathBuf, Vec<u8>>) {
+        let Ok(entries) = fs::read_dir(dir) else {
+            return;
+        };
+        for entry in entries {
+            let path = entry.unwrap().path();
+            if path.is_dir() {
+                visit(root, &path, files);
+            } else {
+                let relative_path = path.strip_prefix(root).unwrap().to_path_buf();
+                files.insert(relative_path, fs::read(&path).unwrap());
+            }
+        }
+    }
+
+    let mut files = BTreeMap::new();
+    visit(root, root, &mut files);
+    files
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn write_files(name: &str, files: &[(&str, &str)]) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!(
+            "portone-client-sdk-check-{name}-{}",
+            std::process::id()
+        ));
+        if dir.exists() {
+            fs::remove_dir_all(&dir).unwrap();
+        }
+        fs::create_dir_all(&dir).unwrap();
+        for (path, content) in files {
+            let path = dir.join(path);
+            fs::create_dir_all(path.parent().unwrap()).unwrap();
+            fs::write(path, content).unwrap();
+        }
+        dir
+    }
+
+    #[test]
+    fn reports_added_removed_and_changed_files() {
+        let header = format!("{GENERATED_HEADER}\n");
+        let generated_dir = write_files(
+            "generated",
+            &[
+                ("entity/Bank.swift", &format!("{header}enum Bank {{}}\n")),
+                (
+                    "entity/Country.swift",
+                    &format!("{header}enum Country {{}}\n"),
+                ),
+                ("PortOne.swift", &format!("{header}protocol PortOne {{}}\n")),
+            ],
+        );
+        let existing_dir = write_files(
+            "existing",
+            &[
+                ("entity/Bank.swift", &format!("{header}enum Bank {{}}\n")),
+                (
+                    "entity/Country.swift",
+                    &format!("{header}struct Country {{}}\n"),
+                ),
+                (
+                    "entity/Locale.swift",
+                    &format!("{header}enum Locale {{}}\n"),
+                ),
+                ("Extensions.swift", "extension String {}\n"),
+            ],
+        );
+        let report = compare(
+            &collect_files(&generated_dir),
+            &collect_files(&existing_dir),
+        );
+        fs::remove_dir_all(&generated_dir).unwrap();
+        fs::remove_dir_all(&existing_dir).unwrap();
+
+        assert_eq!(report.added, [PathBuf::from("PortOne.swift")]);
+        // 헤더가 없는 `Extensions.swift`는 직접 작성한 파일이므로 제외
+        assert_eq!(report.removed, [PathBuf::from("entity/Locale.swift")]);
+        assert_eq!(report.changed, [PathBuf::from("entity/Country.swift")]);
+        assert!(!report.is_up_to_date());
+        assert_eq!(
+            report.to_string(),
+            "added: PortOne.swift\nremoved: entity/Locale.swift\nchanged: entity/Country.swift\n"
+        );
+    }
+
+    #[test]
+    fn missing_out_dir_reports_all_files_as_added() {
+        let generated_dir = write_files("missing", &[("index.ts", "export {};\n")]);
+        let report = compare(
+            &collect_files(&generated_dir),
+            &collect_files(&generated_dir.join("missing")),
+        );
+        fs::remove_dir_all(&generated_dir).unwrap();
+
+        assert_eq!(report.added, [PathBuf::from("index.ts")]);
+        assert!(report.removed.is_empty() && report.changed.is_empty());
+    }
+
+    #[test]
+    fn identical_directories_are_up_to_date() {
+        let files = [("lib/bank.dart", "enum Bank {}\n")];
+        let generated_dir = write_files("identical-generated", &files);
+        let existing_dir = write_files("identical-existing", &files);
+        let report = compare(
+            &collect_files(&generated_dir),
+            &collect_files(&existing_dir),
+        );
+        fs::remove_dir_all(&generated_dir).unwrap();
+        fs::remove_dir_all(&existing_dir).unwrap();
+
+        assert!(report.is_up_to_date());
+        assert_eq!(report.to_string(), "");
+    }
+
+    #[test]
+    fn generates_next_to_out_dir() {
+        let out_dir = write_files("sibling", &[("index.ts", "export {};\n")]);
+        let parent = out_dir
+            .canonicalize()
+            .unwrap()
+            .parent()
+            .unwrap()
+            .to_path_buf();
+        let mut scratch = PathBuf::new();
+        let report = check(&out_dir, |temp_dir| {
+            scratch = temp_dir.clone();
+            fs::write(temp_dir.join("index.ts"), "export {};\n").unwrap();
+        });
+        fs::remove_dir_all(&out_dir).unwrap();
+
+        // 포매터가 `out_dir`의 상위 디렉토리에 있는 설정 파일을 찾을 수 있어야 함
+        assert_eq!(scratch.parent(), Some(parent.as_path()));
+        assert!(!scratch.exists());
+        assert!(report.is_up_to_date());
+    }
+}
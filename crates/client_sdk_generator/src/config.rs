@@ -0,0 +1,107 @@
+use std::path::{Path, PathBuf};
+
+use client_sdk_schema::Version;
+use serde::Deserialize;
+
+use crate::Generator;
+
+/// `portone-client-sdk.config.yml` 설정 파일
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct Config {
+    /// 한 번에 생성할 타겟 목록
+    pub targets: Vec<Target>,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Target {
+    pub generator: Generator,
+    /// 생성된 코드가 저장될 디렉토리 (설정 파일 기준 상대 경로)
+    pub out_dir: PathBuf,
+    /// Dart/Kotlin의 import 기준 경로 또는 Swift의 모듈 이름
+    pub package: Option<String>,
+    /// 코드 생성 후 실행할 포매터 명령어 (마지막 인자로 `out_dir`이 추가됨)
+    pub formatter: Option<Vec<String>>,
+    /// 선택한 플래그(PG)에서 보이는 항목만 생성
+    pub flags: Option<Vec<String>>,
+    /// 주어진 버전의 API만 생성
+    pub target_version: Option<Version>,
+    /// TypeScript 타입마다 `validate{Name}` 함수를 생성하고 개발 모드에서 요청 값을 검사
+    #[serde(default)]
+    pub validators: bool,
+}
+
+impl Config {
+    pub fn load(path: &Path) -> Config {
+        let config = std::fs::read_to_string(path).unwrap_or_else(|e| {
+            eprintln!("error: failed to read {}: {e}", path.display());
+            std::process::exit(1);
+        });
+        let mut config: Config = serde_yaml_ng::from_str(&config).unwrap_or_else(|e| {
+            eprintln!("error: failed to parse {}: {e}", path.display());
+            std::process::exit(1);
+        });
+        let base_dir = path.parent().unwrap_or(Path::new(""));
+        for target in config.targets.iter_mut() {
+            target.out_dir = base_dir.join(&target.out_dir);
+        }
+        config
+    }
+}
+
+impl Target {
+    pub fn new(generator: Generator, out_dir: PathBuf) -> Self {
+        Self {
+            generator,
+            out_dir,
+            package: None,
+            formatter: None,
+            flags: None,
+            target_version: None,
+            validators: false,
+        }
+    }
+
+    pub fn package(&self) -> &str {
+        match &self.package {
+            Some(package) => package,
+            None => match self.generator {
+                Generator::TypeScript => "",
+                Generator::Dart => "package:portone_flutter/v2/model",
+                Generator::Kotlin => "io/portone/sdk/android/type",
+                Generator::Swift => "PortOneSDK",
+            },
+        }
+    }
+
+    pub fn run_formatter(&self, out_dir: &Path) {
+        let (command, optional) = match &self.formatter {
+            Some(command) => (command.clone(), false),
+            None => match self.generator {
+                Generator::TypeScript => return,
+                Generator::Dart => (vec!["dart".into(), "format".into()], false),
+                Generator::Kotlin => (vec!["ktlint".into(), "-F".into()], true),
+                Generator::Swift => (vec!["swiftformat".into()], true),
+            },
+        };
+        let Some((program, args)) = command.split_first() else {
+            return;
+        };
+        // 기본 포매터 중 ktlint, swiftformat은 설치되어 있을 때만 실행
+        match std::process::Command::new(program)
+            .args(args)
+            .arg(out_dir)
+            .spawn()
+        {
+            Ok(mut child) => {
+                child.wait().unwrap();
+            }
+            Err(_) if optional => {}
+            Err(e) => {
+                eprintln!("error: failed to run formatter `{program}`: {e}");
+                std::process::exit(1);
+            }
+        }
+    }
+}
@@ -1,17 +1,24 @@
-use std::path::PathBuf;
+mod changelog;
+mod check;
+mod config;
+
+use std::path::{Path, PathBuf};
 
 use clap::{Parser as ClapParser, Subcommand, ValueEnum};
-use client_sdk_schema::{RESOURCE_INDEX, Schema};
+use client_sdk_schema::{RESOURCE_INDEX, Schema, Severity, Version};
 use client_sdk_ts_codegen::{
     entrypoint::generate_entrypoint_module, generate_resource_module, loader::generate_loader,
-    method::generate_method_modules,
+    method::generate_method_modules, validator::generate_validation_module,
 };
+use config::{Config, Target};
+use serde::Deserialize;
 
 #[derive(ClapParser, Debug)]
 #[clap(name = "client-sdk-generator")]
 struct Args {
     #[command(subcommand)]
     command: Commands,
+    /// 스키마 파일 (YAML, JSON, TOML 중 확장자로 판단하며 `-`이면 표준 입력)
     #[arg(short, long, global = true, default_value = "portone-client-sdk.yml")]
     schema: PathBuf,
 }
@@ -20,13 +27,100 @@ struct Args {
 enum Commands {
     #[clap(name = "generate")]
     Generate {
-        out_dir: PathBuf,
-        #[arg(long, value_enum)]
-        generator: Generator,
+        #[command(flatten)]
+        targets: TargetArgs,
+    },
+    /// 생성된 코드가 스키마와 일치하는지 검사합니다.
+    #[clap(name = "check")]
+    Check {
+        #[command(flatten)]
+        targets: TargetArgs,
+    },
+    /// 두 스키마를 비교하여 변경 사항과 호환성 여부를 출력합니다.
+    #[clap(name = "diff")]
+    Diff { old: PathBuf, new: PathBuf },
+    /// 두 스키마의 변경 사항으로 타겟 SDK별 Markdown 변경 내역을 작성합니다.
+    #[clap(name = "changelog")]
+    Changelog {
+        old: PathBuf,
+        new: PathBuf,
+        /// 변경 내역을 작성할 SDK (지정하지 않으면 설정 파일의 모든 타겟)
+        #[arg(long, value_enum, value_delimiter = ',')]
+        generator: Option<Vec<Generator>>,
+        #[arg(long, default_value = "portone-client-sdk.config.yml")]
+        config: PathBuf,
+        /// 변경 내역을 저장할 파일 (지정하지 않으면 표준 출력)
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+    },
+    /// OpenAPI 3 문서의 `components/schemas`를 스키마 리소스로 변환합니다.
+    #[clap(name = "import-openapi")]
+    ImportOpenapi {
+        /// OpenAPI 문서 (YAML 또는 JSON)
+        input: PathBuf,
+        /// 변환된 리소스를 둘 디렉토리
+        #[arg(long, default_value = "entity")]
+        resource_dir: String,
+        /// 변환된 스키마를 저장할 파일 (지정하지 않으면 표준 출력)
+        #[arg(short, long)]
+        output: Option<PathBuf>,
     },
+    /// 스키마의 리소스를 서버에서 검증에 사용할 수 있는 JSON Schema로 내보냅니다.
+    #[clap(name = "export-json-schema")]
+    ExportJsonSchema {
+        /// JSON Schema를 저장할 파일 (지정하지 않으면 표준 출력)
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+    },
+}
+
+/// `--generator`가 주어지면 단일 타겟을, 그렇지 않으면 설정 파일의 모든 타겟을 사용합니다.
+#[derive(clap::Args, Debug)]
+struct TargetArgs {
+    #[arg(requires = "generator")]
+    out_dir: Option<PathBuf>,
+    #[arg(long, value_enum, requires = "out_dir")]
+    generator: Option<Generator>,
+    #[arg(long, default_value = "portone-client-sdk.config.yml")]
+    config: PathBuf,
+    /// 선택한 플래그(PG)에서 보이는 항목만 생성 (설정 파일의 `flags`보다 우선)
+    #[arg(long, value_delimiter = ',')]
+    flags: Option<Vec<String>>,
+    /// 주어진 버전의 API만 생성 (설정 파일의 `targetVersion`보다 우선)
+    #[arg(long)]
+    target_version: Option<Version>,
+    /// TypeScript 타입의 런타임 검사 함수 생성 (설정 파일의 `validators`보다 우선)
+    #[arg(long)]
+    validators: bool,
 }
 
-#[derive(ValueEnum, Clone, Copy, Debug)]
+impl TargetArgs {
+    fn into_targets(self) -> Vec<Target> {
+        let mut targets = match (self.generator, self.out_dir) {
+            (Some(generator), Some(out_dir)) => vec![Target::new(generator, out_dir)],
+            _ => Config::load(&self.config).targets,
+        };
+        if let Some(flags) = self.flags {
+            for target in targets.iter_mut() {
+                target.flags = Some(flags.clone());
+            }
+        }
+        if let Some(version) = self.target_version {
+            for target in targets.iter_mut() {
+                target.target_version = Some(version);
+            }
+        }
+        if self.validators {
+            for target in targets.iter_mut() {
+                target.validators = true;
+            }
+        }
+        targets
+    }
+}
+
+#[derive(ValueEnum, Deserialize, Clone, Copy, Debug)]
+#[serde(rename_all = "lowercase")]
 enum Generator {
     #[clap(name = "typescript")]
     TypeScript,
@@ -38,96 +132,239 @@ enum Generator {
     Swift,
 }
 
-fn load_schema(path: &PathBuf) -> Schema {
-    let schema = std::fs::read_to_string(path).unwrap();
-    serde_yaml_ng::from_str(&schema).unwrap()
+fn load_schema(path: &Path) -> Schema {
+    let file = Schema::load(path).unwrap_or_else(|e| {
+        eprintln!("error: {e}");
+        std::process::exit(1);
+    });
+
+    // 코드젠 도중 패닉이 발생하지 않도록 스키마 전체를 먼저 검사
+    let errors = file.schema.validate();
+    for error in &errors {
+        match file.source_of(&error.path) {
+            Some(include) => eprintln!(
+                "{}: {error} (defined in {})",
+                error.severity(),
+                include.source
+            ),
+            None => eprintln!("{}: {error}", error.severity()),
+        }
+    }
+    let error_count = errors
+        .iter()
+        .filter(|error| error.severity() == Severity::Error)
+        .count();
+    if error_count > 0 {
+        eprintln!("{error_count} error(s) found in {}", path.display());
+        std::process::exit(1);
+    }
+    file.schema
 }
 
 fn main() {
     let args = Args::parse();
     match args.command {
-        Commands::Generate { out_dir, generator } => {
-            println!("Generating code to {out_dir:?}");
-            match generator {
-                Generator::TypeScript => {
-                    println!("Generating TypeScript code");
-                    let schema: Schema = load_schema(&args.schema);
-                    let resource_index = schema.build_resource_index();
-                    RESOURCE_INDEX.set(&resource_index, || {
-                        generate_resource_module(&out_dir, "", &schema.resources, &out_dir);
-                        generate_method_modules(&out_dir, &schema.methods);
-                        generate_loader(&out_dir, &schema.methods);
-                        generate_entrypoint_module(&out_dir, &schema.methods);
-                    });
-                }
-                Generator::Dart => {
-                    println!("Generating Dart code");
-                    let schema: Schema = load_schema(&args.schema);
-                    let resource_index = schema.build_resource_index();
-                    RESOURCE_INDEX.set(&resource_index, || {
-                        client_sdk_dart_codegen::generate_resources_module(
-                            &schema.resources,
-                            &out_dir,
-                            "package:portone_flutter/v2/model",
-                        );
-                    });
-                    let mut child = std::process::Command::new("dart")
-                        .arg("format")
-                        .arg(&out_dir)
-                        .spawn()
-                        .unwrap();
-                    child.wait().unwrap();
-                }
-                Generator::Kotlin => {
-                    println!("Generating Kotlin code");
-                    let schema: Schema = load_schema(&args.schema);
-                    let resource_index = schema.build_resource_index();
-                    RESOURCE_INDEX.set(&resource_index, || {
-                        client_sdk_kotlin_codegen::generate_resources_module(
-                            &schema.resources,
-                            &out_dir,
-                            "io/portone/sdk/android/type",
-                        );
-                    });
-                    // Optional: Run ktlint formatter if available
-                    if std::process::Command::new("ktlint")
-                        .arg("--version")
-                        .output()
-                        .is_ok()
-                    {
-                        let mut child = std::process::Command::new("ktlint")
-                            .arg("-F")
-                            .arg(&out_dir)
-                            .spawn()
-                            .unwrap();
-                        child.wait().unwrap();
-                    }
-                }
-                Generator::Swift => {
-                    println!("Generating Swift code");
-                    let schema: Schema = load_schema(&args.schema);
-                    let resource_index = schema.build_resource_index();
-                    RESOURCE_INDEX.set(&resource_index, || {
-                        client_sdk_swift_codegen::generate_resources_module(
-                            &schema.resources,
-                            &out_dir,
-                            "PortOneSDK",
-                        );
-                    });
-                    // Optional: Run swiftformat if available
-                    if std::process::Command::new("swiftformat")
-                        .arg("--version")
-                        .output()
-                        .is_ok()
-                    {
-                        let mut child = std::process::Command::new("swiftformat")
-                            .arg(&out_dir)
-                            .spawn()
-                            .unwrap();
-                        child.wait().unwrap();
-                    }
+        Commands::Generate { targets } => {
+            let targets = targets.into_targets();
+            let schema = load_schema(&args.schema);
+            for target in &targets {
+                println!("Generating code to {:?}", target.out_dir);
+                generate(target, &schema, &target.out_dir);
+            }
+        }
+        Commands::Check { targets } => {
+            let targets = targets.into_targets();
+            let schema = load_schema(&args.schema);
+            let mut is_up_to_date = true;
+            for target in &targets {
+                let out_dir = &target.out_dir;
+                let report = check::check(out_dir, |temp_dir| generate(target, &schema, temp_dir));
+                if report.is_up_to_date() {
+                    println!("Generated code in {out_dir:?} is up to date");
+                } else {
+                    eprint!("{report}");
+                    eprintln!(
+                        "Generated code in {out_dir:?} is out of date. Run `generate` to update it."
+                    );
+                    is_up_to_date = false;
                 }
             }
+            if !is_up_to_date {
+                std::process::exit(1);
+            }
+        }
+        Commands::Diff { old, new } => {
+            let diff = load_schema(&old).diff(&load_schema(&new));
+            for change in &diff.changes {
+                let label = if change.breaking {
+                    "breaking"
+                } else {
+                    "non-breaking"
+                };
+                println!("{label}: {change}");
+            }
+            println!("Suggested version bump: {}", diff.suggested_bump());
+        }
+        Commands::Changelog {
+            old,
+            new,
+            generator,
+            config,
+            output,
+        } => {
+            let targets = match generator {
+                Some(generators) => generators
+                    .into_iter()
+                    .map(|generator| Target::new(generator, PathBuf::new()))
+                    .collect(),
+                None => Config::load(&config).targets,
+            };
+            let (old, new) = (load_schema(&old), load_schema(&new));
+            let mut changelog = String::new();
+            for target in &targets {
+                let diff = changelog::diff(
+                    target.generator,
+                    filter_schema(target, &old),
+                    filter_schema(target, &new),
+                );
+                changelog::write_section(&mut changelog, target.generator, &diff);
+            }
+            write_output(output.as_deref(), &changelog);
+        }
+        Commands::ImportOpenapi {
+            input,
+            resource_dir,
+            output,
+        } => {
+            let document = std::fs::read_to_string(&input)
+                .map_err(|e| format!("failed to read {}: {e}", input.display()))
+                .and_then(|document| {
+                    serde_yaml_ng::from_str(&document)
+                        .map_err(|e| format!("failed to parse {}: {e}", input.display()))
+                })
+                .and_then(|document| Schema::from_openapi(&document, &resource_dir))
+                .unwrap_or_else(|e| {
+                    eprintln!("error: {e}");
+                    std::process::exit(1);
+                });
+            for warning in &document.warnings {
+                eprintln!("warning: {warning}");
+            }
+            write_output(
+                output.as_deref(),
+                &serde_yaml_ng::to_string(&document.schema).unwrap(),
+            );
+        }
+        Commands::ExportJsonSchema { output } => {
+            let json_schema = load_schema(&args.schema).to_json_schema();
+            write_output(
+                output.as_deref(),
+                &serde_json::to_string_pretty(&json_schema).unwrap(),
+            );
+        }
+    }
+}
+
+/// `path`가 주어지면 파일에, 그렇지 않으면 표준 출력에 씁니다.
+fn write_output(path: Option<&Path>, content: &str) {
+    match path {
+        Some(path) => std::fs::write(path, content).unwrap_or_else(|e| {
+            eprintln!("error: failed to write {}: {e}", path.display());
+            std::process::exit(1);
+        }),
+        None => print!("{content}"),
+    }
+}
+
+/// 타겟의 플래그와 버전에 해당하는 항목만 남긴 스키마를 만듭니다.
+///
+/// 타겟 버전을 지정하지 않으면 스키마의 `version`을 사용합니다.
+fn filter_schema(target: &Target, schema: &Schema) -> Schema {
+    let mut schema = schema.clone();
+    if let Some(flags) = &target.flags
+        && let Err(e) = schema.retain_flags(flags)
+    {
+        eprintln!("error: {e}");
+        std::process::exit(1);
+    }
+    if let Some(version) = target.target_version.or(schema.version)
+        && let Err(e) = schema.retain_version(&version)
+    {
+        eprintln!("error: {e}");
+        std::process::exit(1);
+    }
+    schema
+}
+
+fn generate(target: &Target, schema: &Schema, out_dir: &PathBuf) {
+    let mut schema = filter_schema(target, schema);
+    // 모바일 SDK는 인라인 객체 타입을 이름이 있는 타입으로 분리하여 생성
+    // (분리된 타입이 프로퍼티의 사용 가능 버전 안내를 복사하지 않도록 먼저 분리)
+    if !matches!(target.generator, Generator::TypeScript) {
+        schema.hoist_inline_types();
+    }
+    schema.document_availability();
+    let schema = &schema;
+    let resource_index = schema.build_resource_index();
+    match target.generator {
+        Generator::TypeScript => {
+            println!("Generating TypeScript code");
+            RESOURCE_INDEX.set(&resource_index, || {
+                let validators = target.validators;
+                generate_resource_module(out_dir, "", &schema.resources, out_dir, validators);
+                generate_method_modules(out_dir, &schema.methods, validators);
+                generate_loader(out_dir, &schema.methods);
+                if validators {
+                    generate_validation_module(out_dir);
+                }
+                generate_entrypoint_module(out_dir, &schema.methods, validators);
+            });
+        }
+        Generator::Dart => {
+            println!("Generating Dart code");
+            RESOURCE_INDEX.set(&resource_index, || {
+                client_sdk_dart_codegen::generate_resources_module(
+                    &schema.resources,
+                    out_dir,
+                    target.package(),
+                );
+                client_sdk_dart_codegen::generate_methods_module(
+                    &schema.methods,
+                    out_dir,
+                    target.package(),
+                );
+            });
+        }
+        Generator::Kotlin => {
+            println!("Generating Kotlin code");
+            RESOURCE_INDEX.set(&resource_index, || {
+                client_sdk_kotlin_codegen::generate_resources_module(
+                    &schema.resources,
+                    out_dir,
+                    target.package(),
+                );
+                client_sdk_kotlin_codegen::generate_methods_module(
+                    &schema.methods,
+                    out_dir,
+                    target.package(),
+                );
+            });
+        }
+        Generator::Swift => {
+            println!("Generating Swift code");
+            RESOURCE_INDEX.set(&resource_index, || {
+                client_sdk_swift_codegen::generate_resources_module(
+                    &schema.resources,
+                    out_dir,
+                    target.package(),
+                );
+                client_sdk_swift_codegen::generate_methods_module(
+                    &schema.resources,
+                    &schema.methods,
+                    out_dir,
+                );
+            });
         }
     }
+    target.run_formatter(out_dir);
 }
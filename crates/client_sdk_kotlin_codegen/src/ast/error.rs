@@ -0,0 +1,157 @@
+use std::fmt;
+
+use super::{Comment, Identifier, Indent, ObjectField};
+
+/// 모든 에러 클래스가 상속하는 기본 클래스 이름
+pub const BASE_ERROR_NAME: &str = "PortOneError";
+
+#[derive(Debug, Clone)]
+pub struct ErrorClass {
+    pub name: Identifier,
+    pub description: Option<Comment>,
+    pub transaction_type: Option<String>,
+    pub fields: Vec<ObjectField>,
+}
+
+impl fmt::Display for ErrorClass {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(ref desc) = self.description {
+            writeln!(f, "/**")?;
+            for line in desc.lines() {
+                writeln!(f, " * {line}")?;
+            }
+            writeln!(f, " */")?;
+        }
+
+        // Throwable.message와 이름이 같은 필드는 override하여 상위 클래스로 전달
+        let has_message = self
+            .fields
+            .iter()
+            .any(|field| field.name.as_ref() == "message");
+        let super_call = if has_message {
+            format!("{BASE_ERROR_NAME}(message)")
+        } else {
+            format!("{BASE_ERROR_NAME}(null)")
+        };
+        if self.fields.is_empty() {
+            write!(f, "class {name} : {super_call}", name = self.name.as_ref())?;
+        } else {
+            writeln!(f, "class {name}(", name = self.name.as_ref())?;
+            {
+                let indent = Indent(1);
+                for (i, field) in self.fields.iter().enumerate() {
+                    let terminator = if i + 1 == self.fields.len() { "" } else { "," };
+                    if let Some(ref desc) = field.description {
+                        writeln!(f, "{indent}/**")?;
+                        for line in desc.lines() {
+                            writeln!(f, "{indent} * {line}")?;
+                        }
+                        writeln!(f, "{indent} */")?;
+                    }
+                    let modifier = if field.name.as_ref() == "message" {
+                        "override "
+                    } else {
+                        ""
+                    };
+                    let default_value = field.default_argument();
+                    writeln!(
+                        f,
+                        "{indent}{modifier}val {name}: {value_type}{default_value}{terminator}",
+                        name = field.name.as_ref(),
+                        value_type = field.value_type,
+                    )?;
+                }
+            }
+            write!(f, ") : {super_call}")?;
+        }
+
+        match &self.transaction_type {
+            Some(transaction_type) => {
+                writeln!(f, " {{")?;
+                {
+                    let indent = Indent(1);
+                    writeln!(
+                        f,
+                        "{indent}override val transactionType: String? = \"{transaction_type}\""
+                    )?;
+                }
+                writeln!(f, "}}")
+            }
+            None => writeln!(f),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ast::{CompositeType, ScalarType};
+
+    use super::*;
+
+    #[test]
+    fn error_class() {
+        let error = ErrorClass {
+            name: Identifier::try_from("PaymentError").unwrap(),
+            description: Some(Comment::try_from("결제 실패 시 발생하는 에러").unwrap()),
+            transaction_type: Some("PAYMENT".to_string()),
+            fields: vec![
+                ObjectField {
+                    name: Identifier::try_from("paymentId").unwrap(),
+                    serialized_name: "paymentId".to_string(),
+                    value_type: CompositeType {
+                        scalar: ScalarType::String,
+                        is_list: false,
+                        is_required: false,
+                    },
+                    description: None,
+                    hide_if_empty: false,
+                    deprecated: false,
+                    default_value: None,
+                },
+                ObjectField {
+                    name: Identifier::try_from("message").unwrap(),
+                    serialized_name: "message".to_string(),
+                    value_type: CompositeType {
+                        scalar: ScalarType::String,
+                        is_list: false,
+                        is_required: true,
+                    },
+                    description: Some(Comment::try_from("에러 메시지").unwrap()),
+                    hide_if_empty: false,
+                    deprecated: false,
+                    default_value: None,
+                },
+            ],
+        };
+        assert_eq!(
+            error.to_string(),
+            r#"/**
+ * 결제 실패 시 발생하는 에러
+ */
+class PaymentError(
+    val paymentId: String? = null,
+    /**
+     * 에러 메시지
+     */
+    override val message: String
+) : PortOneError(message) {
+    override val transactionType: String? = "PAYMENT"
+}
+"#
+        );
+    }
+
+    #[test]
+    fn error_class_without_fields() {
+        let error = ErrorClass {
+            name: Identifier::try_from("UnknownError").unwrap(),
+            description: None,
+            transaction_type: None,
+            fields: vec![],
+        };
+        assert_eq!(
+            error.to_string(),
+            "class UnknownError : PortOneError(null)\n"
+        );
+    }
+}
@@ -1,6 +1,9 @@
 use std::fmt;
 
-use super::{Comment, Identifier, Indent, ObjectField, ScalarType, TypeReference, UnionParent};
+use super::{
+    Comment, DeprecatedAnnotation, Identifier, Indent, ObjectField, TypeReference, UnionParent,
+    object::PutField,
+};
 
 #[derive(Debug, Clone)]
 pub struct Intersection {
@@ -9,6 +12,7 @@ pub struct Intersection {
     pub constituents: Vec<IntersectionConstituent>,
     pub fields: Vec<ObjectField>, // Flattened fields from all constituents
     pub union_parents: Vec<UnionParent>,
+    pub deprecated: bool,
 }
 
 #[derive(Debug, Clone)]
@@ -27,6 +31,9 @@ impl fmt::Display for Intersection {
             }
             writeln!(f, " */")?;
         }
+        if self.deprecated {
+            writeln!(f, "{}", DeprecatedAnnotation(None))?;
+        }
 
         // Data class declaration with flattened fields
         writeln!(f, "@Parcelize")?;
@@ -55,26 +62,7 @@ impl fmt::Display for Intersection {
             {
                 let indent = Indent(2);
                 for field in self.fields.iter() {
-                    let to_json = ToJson {
-                        name: field.name.as_ref(),
-                        is_list: field.value_type.is_list,
-                        scalar: &field.value_type.scalar,
-                    };
-
-                    if field.value_type.is_required {
-                        writeln!(
-                            f,
-                            "{indent}put(\"{serialized_name}\", {to_json})",
-                            serialized_name = field.serialized_name
-                        )?;
-                    } else {
-                        writeln!(
-                            f,
-                            "{indent}{field_name}?.let {{ put(\"{serialized_name}\", {to_json}) }}",
-                            serialized_name = field.serialized_name,
-                            field_name = field.name.as_ref()
-                        )?;
-                    }
+                    writeln!(f, "{indent}{}", PutField(field))?;
                 }
             }
             writeln!(f, "{indent}}}")?;
@@ -84,33 +72,9 @@ impl fmt::Display for Intersection {
     }
 }
 
-struct ToJson<'a> {
-    name: &'a str,
-    is_list: bool,
-    scalar: &'a ScalarType,
-}
-
-impl fmt::Display for ToJson<'_> {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        let name = self.name;
-        match self.scalar {
-            ScalarType::Long | ScalarType::Boolean | ScalarType::Json | ScalarType::String => {
-                write!(f, "{name}")
-            }
-            ScalarType::TypeReference(_) => {
-                if self.is_list {
-                    write!(f, "{name}.map {{ it.toJson() }}")
-                } else {
-                    write!(f, "{name}.toJson()")
-                }
-            }
-        }
-    }
-}
-
 #[cfg(test)]
 mod tests {
-    use crate::ast::CompositeType;
+    use crate::ast::{CompositeType, ScalarType};
 
     use super::*;
 
@@ -146,6 +110,9 @@ mod tests {
                         is_required: true,
                     },
                     description: Some(Comment::try_from("결제 금액").unwrap()),
+                    hide_if_empty: false,
+                    deprecated: false,
+                    default_value: None,
                 },
                 ObjectField {
                     name: Identifier::try_from("currency").unwrap(),
@@ -156,6 +123,9 @@ mod tests {
                         is_required: true,
                     },
                     description: Some(Comment::try_from("통화 코드").unwrap()),
+                    hide_if_empty: false,
+                    deprecated: false,
+                    default_value: None,
                 },
                 // Fields from PaymentRequestUnion
                 ObjectField {
@@ -170,6 +140,9 @@ mod tests {
                         is_required: true,
                     },
                     description: Some(Comment::try_from("결제 수단").unwrap()),
+                    hide_if_empty: false,
+                    deprecated: false,
+                    default_value: None,
                 },
                 ObjectField {
                     name: Identifier::try_from("cardInfo").unwrap(),
@@ -183,9 +156,13 @@ mod tests {
                         is_required: false,
                     },
                     description: Some(Comment::try_from("카드 정보").unwrap()),
+                    hide_if_empty: false,
+                    deprecated: false,
+                    default_value: None,
                 },
             ],
             union_parents: vec![],
+            deprecated: false,
         };
 
         assert_eq!(
@@ -0,0 +1,256 @@
+use std::fmt;
+
+use super::{Comment, CompositeType, DeprecatedAnnotation, Identifier, Indent};
+
+/// 스키마의 메소드 목록을 모은 SDK 진입점 interface
+#[derive(Debug, Clone)]
+pub struct MethodFacade {
+    pub name: Identifier,
+    pub methods: Vec<Method>,
+}
+
+#[derive(Debug, Clone)]
+pub struct Method {
+    pub name: Identifier,
+    pub description: Option<Comment>,
+    pub input: CompositeType,
+    pub output: Option<CompositeType>,
+    pub callbacks: Option<Callbacks>,
+    pub deprecated: bool,
+    pub replacement: Option<String>,
+}
+
+/// 메소드의 콜백 목록을 모은 interface
+#[derive(Debug, Clone)]
+pub struct Callbacks {
+    pub name: Identifier,
+    pub callbacks: Vec<Callback>,
+}
+
+#[derive(Debug, Clone)]
+pub struct Callback {
+    pub name: Identifier,
+    pub description: Option<Comment>,
+    pub parameters: Vec<CallbackParameter>,
+    pub deprecated: bool,
+    pub replacement: Option<String>,
+}
+
+#[derive(Debug, Clone)]
+pub struct CallbackParameter {
+    pub name: Identifier,
+    pub value_type: CompositeType,
+}
+
+fn write_doc(f: &mut fmt::Formatter<'_>, indent: Indent, comment: &Option<Comment>) -> fmt::Result {
+    if let Some(desc) = comment {
+        writeln!(f, "{indent}/**")?;
+        for line in desc.lines() {
+            writeln!(f, "{indent} * {line}")?;
+        }
+        writeln!(f, "{indent} */")?;
+    }
+    Ok(())
+}
+
+impl fmt::Display for MethodFacade {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "interface {name} {{", name = self.name.as_ref())?;
+        {
+            let indent = Indent(1);
+            for method in self.methods.iter() {
+                write_doc(f, indent, &method.description)?;
+                if method.deprecated {
+                    writeln!(
+                        f,
+                        "{indent}{}",
+                        DeprecatedAnnotation(method.replacement.as_deref())
+                    )?;
+                }
+                write!(
+                    f,
+                    "{indent}suspend fun {name}(request: {input}",
+                    name = method.name.as_ref(),
+                    input = method.input
+                )?;
+                if let Some(callbacks) = &method.callbacks {
+                    write!(f, ", callbacks: {}", callbacks.name.as_ref())?;
+                }
+                match &method.output {
+                    Some(output) => writeln!(f, "): {output}")?,
+                    None => writeln!(f, ")")?,
+                }
+            }
+        }
+        writeln!(f, "}}")?;
+
+        for callbacks in self.methods.iter().filter_map(|m| m.callbacks.as_ref()) {
+            writeln!(f)?;
+            write!(f, "{callbacks}")?;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for Callbacks {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "interface {name} {{", name = self.name.as_ref())?;
+        {
+            let indent = Indent(1);
+            for callback in self.callbacks.iter() {
+                write_doc(f, indent, &callback.description)?;
+                if callback.deprecated {
+                    writeln!(
+                        f,
+                        "{indent}{}",
+                        DeprecatedAnnotation(callback.replacement.as_deref())
+                    )?;
+                }
+                let parameters = callback
+                    .parameters
+                    .iter()
+                    .map(|parameter| format!("{}: {}", parameter.name, parameter.value_type))
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                writeln!(
+                    f,
+                    "{indent}fun {name}({parameters})",
+                    name = callback.name.as_ref()
+                )?;
+            }
+        }
+        writeln!(f, "}}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ast::{ScalarType, TypeReference};
+
+    use super::*;
+
+    fn type_reference(name: &str, is_required: bool) -> CompositeType {
+        CompositeType {
+            scalar: ScalarType::TypeReference(TypeReference {
+                name: Identifier::try_from(name).unwrap(),
+                path: "".into(),
+            }),
+            is_list: false,
+            is_required,
+        }
+    }
+
+    #[test]
+    fn method_facade() {
+        let facade = MethodFacade {
+            name: Identifier::try_from("PortOne").unwrap(),
+            methods: vec![
+                Method {
+                    name: Identifier::try_from("requestPayment").unwrap(),
+                    description: Some(Comment::try_from("결제를 요청합니다.").unwrap()),
+                    input: type_reference("PaymentRequest", true),
+                    output: Some(type_reference("PaymentResponse", false)),
+                    callbacks: None,
+                    deprecated: false,
+                    replacement: None,
+                },
+                Method {
+                    name: Identifier::try_from("loadPaymentUI").unwrap(),
+                    description: None,
+                    input: type_reference("LoadPaymentUIRequest", true),
+                    output: None,
+                    callbacks: Some(Callbacks {
+                        name: Identifier::try_from("LoadPaymentUICallbacks").unwrap(),
+                        callbacks: vec![
+                            Callback {
+                                name: Identifier::try_from("onPaymentSuccess").unwrap(),
+                                description: Some(
+                                    Comment::try_from("결제 성공 시 호출됩니다.").unwrap(),
+                                ),
+                                parameters: vec![CallbackParameter {
+                                    name: Identifier::try_from("response").unwrap(),
+                                    value_type: type_reference("PaymentResponse", true),
+                                }],
+                                deprecated: false,
+                                replacement: None,
+                            },
+                            Callback {
+                                name: Identifier::try_from("onPaymentFail").unwrap(),
+                                description: None,
+                                parameters: vec![CallbackParameter {
+                                    name: Identifier::try_from("error").unwrap(),
+                                    value_type: CompositeType {
+                                        scalar: ScalarType::Exception,
+                                        is_list: false,
+                                        is_required: true,
+                                    },
+                                }],
+                                deprecated: false,
+                                replacement: None,
+                            },
+                        ],
+                    }),
+                    deprecated: false,
+                    replacement: None,
+                },
+            ],
+        };
+        assert_eq!(
+            facade.to_string(),
+            r#"interface PortOne {
+    /**
+     * 결제를 요청합니다.
+     */
+    suspend fun requestPayment(request: PaymentRequest): PaymentResponse?
+    suspend fun loadPaymentUI(request: LoadPaymentUIRequest, callbacks: LoadPaymentUICallbacks)
+}
+
+interface LoadPaymentUICallbacks {
+    /**
+     * 결제 성공 시 호출됩니다.
+     */
+    fun onPaymentSuccess(response: PaymentResponse)
+    fun onPaymentFail(error: Exception)
+}
+"#
+        );
+    }
+
+    #[test]
+    fn deprecated_method() {
+        let facade = MethodFacade {
+            name: Identifier::try_from("PortOne").unwrap(),
+            methods: vec![Method {
+                name: Identifier::try_from("requestPaymentLegacy").unwrap(),
+                description: None,
+                input: type_reference("PaymentRequest", true),
+                output: None,
+                callbacks: Some(Callbacks {
+                    name: Identifier::try_from("RequestPaymentLegacyCallbacks").unwrap(),
+                    callbacks: vec![Callback {
+                        name: Identifier::try_from("onDone").unwrap(),
+                        description: None,
+                        parameters: vec![],
+                        deprecated: true,
+                        replacement: None,
+                    }],
+                }),
+                deprecated: true,
+                replacement: Some("requestPayment".to_string()),
+            }],
+        };
+        assert_eq!(
+            facade.to_string(),
+            r#"interface PortOne {
+    @Deprecated("Use `requestPayment` instead.")
+    suspend fun requestPaymentLegacy(request: PaymentRequest, callbacks: RequestPaymentLegacyCallbacks)
+}
+
+interface RequestPaymentLegacyCallbacks {
+    @Deprecated("This API is deprecated and will be removed in a future version.")
+    fun onDone()
+}
+"#
+        );
+    }
+}
@@ -1,18 +1,43 @@
 use std::fmt;
 
 mod r#enum;
+mod error;
 mod ident;
 mod intersection;
+mod method;
 mod object;
 mod union;
 
 use client_sdk_utils::{MdastNodeExt, ToMdastExt};
 pub use r#enum::*;
+pub use error::*;
 pub use ident::*;
 pub use intersection::*;
+pub use method::*;
 pub use object::*;
 pub use union::*;
 
+/// 더 이상 사용되지 않는 항목에 붙이는 어노테이션
+///
+/// 대체 항목이 있으면 메시지에서 안내합니다.
+pub struct DeprecatedAnnotation<'a>(pub Option<&'a str>);
+
+impl fmt::Display for DeprecatedAnnotation<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.0 {
+            Some(replacement) => write!(
+                f,
+                "@Deprecated({})",
+                string_literal(&format!("Use `{replacement}` instead."))
+            ),
+            None => write!(
+                f,
+                "@Deprecated(\"This API is deprecated and will be removed in a future version.\")"
+            ),
+        }
+    }
+}
+
 #[derive(Debug, Clone, Copy)]
 pub struct Indent(pub usize);
 
@@ -59,23 +84,55 @@ pub struct TypeReference {
     pub name: Identifier,
 }
 
+/// 값을 Kotlin 문자열 리터럴로 변환합니다.
+pub fn string_literal(value: &str) -> String {
+    let escaped = value
+        .replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('$', "\\$")
+        .replace('\n', "\\n");
+    format!("\"{escaped}\"")
+}
+
 #[derive(Debug, Clone)]
 pub enum ScalarType {
     Long,
+    Double,
+    /// 문자열로 직렬화되는 `java.math.BigDecimal`
+    Decimal,
     Boolean,
     Json,
     String,
+    /// 콜백으로 전달되는 에러
+    Exception,
     TypeReference(TypeReference),
+    /// 문자열 키를 가진 `Map`
+    Map(Box<ScalarType>),
+    /// 배열의 항목으로 쓰이는 `List`
+    List(Box<ScalarType>),
 }
 
 impl ScalarType {
-    pub fn to_identifier(&self) -> &str {
+    pub fn to_identifier(&self) -> String {
         match self {
-            ScalarType::Long => "Long",
-            ScalarType::Boolean => "Boolean",
-            ScalarType::Json => "Map<String, Any?>",
-            ScalarType::String => "String",
-            ScalarType::TypeReference(TypeReference { name, .. }) => name.as_ref(),
+            ScalarType::Long => "Long".to_string(),
+            ScalarType::Double => "Double".to_string(),
+            ScalarType::Decimal => "BigDecimal".to_string(),
+            ScalarType::Boolean => "Boolean".to_string(),
+            ScalarType::Json => "Map<String, Any?>".to_string(),
+            ScalarType::String => "String".to_string(),
+            ScalarType::Exception => "Exception".to_string(),
+            ScalarType::TypeReference(TypeReference { name, .. }) => name.as_ref().to_string(),
+            ScalarType::Map(values) => format!("Map<String, {}>", values.to_identifier()),
+            ScalarType::List(items) => format!("List<{}>", items.to_identifier()),
+        }
+    }
+
+    /// 값의 타입 (`Map`이나 `List`이면 원소의 타입)
+    pub fn value_scalar(&self) -> &ScalarType {
+        match self {
+            ScalarType::Map(values) | ScalarType::List(values) => values.value_scalar(),
+            scalar => scalar,
         }
     }
 }
@@ -86,3 +143,27 @@ pub struct CompositeType {
     pub is_list: bool,
     pub is_required: bool,
 }
+
+impl fmt::Display for CompositeType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let nullable = if self.is_required { "" } else { "?" };
+        if self.is_list {
+            write!(f, "List<{}>{nullable}", self.scalar.to_identifier())
+        } else {
+            write!(f, "{}{nullable}", self.scalar.to_identifier())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn deprecated_annotation_escapes_replacement() {
+        assert_eq!(
+            DeprecatedAnnotation(Some("pay(\"$amount\")")).to_string(),
+            r#"@Deprecated("Use `pay(\"\$amount\")` instead.")"#
+        );
+    }
+}
@@ -1,6 +1,8 @@
 use std::fmt;
 
-use super::{Comment, CompositeType, Identifier, Indent, ScalarType, UnionParent};
+use super::{
+    Comment, CompositeType, DeprecatedAnnotation, Identifier, Indent, ScalarType, UnionParent,
+};
 
 #[derive(Debug, Clone)]
 pub struct Object {
@@ -9,6 +11,7 @@ pub struct Object {
     pub fields: Vec<ObjectField>,
     pub union_parents: Vec<UnionParent>,
     pub is_one_of: bool,
+    pub deprecated: bool,
 }
 
 impl fmt::Display for Object {
@@ -20,6 +23,9 @@ impl fmt::Display for Object {
             }
             writeln!(f, " */")?;
         }
+        if self.deprecated {
+            writeln!(f, "{}", DeprecatedAnnotation(None))?;
+        }
 
         if self.fields.is_empty() && !self.is_one_of {
             // Empty object case
@@ -53,8 +59,11 @@ impl fmt::Display for Object {
                     let field_type = if field.value_type.is_list {
                         format!("List<{}>", field.value_type.scalar.to_identifier())
                     } else {
-                        field.value_type.scalar.to_identifier().to_string()
+                        field.value_type.scalar.to_identifier()
                     };
+                    if field.deprecated {
+                        writeln!(f, "{indent}{}", DeprecatedAnnotation(None))?;
+                    }
                     writeln!(f, "{indent}@Parcelize")?;
                     writeln!(
                         f,
@@ -107,26 +116,7 @@ impl fmt::Display for Object {
                 {
                     let indent = Indent(2);
                     for field in self.fields.iter() {
-                        let to_json = ToJson {
-                            name: field.name.as_ref(),
-                            is_list: field.value_type.is_list,
-                            scalar: &field.value_type.scalar,
-                        };
-
-                        if field.value_type.is_required {
-                            writeln!(
-                                f,
-                                "{indent}put(\"{serialized_name}\", {to_json})",
-                                serialized_name = field.serialized_name
-                            )?;
-                        } else {
-                            writeln!(
-                                f,
-                                "{indent}{field_name}?.let {{ put(\"{serialized_name}\", {to_json}) }}",
-                                serialized_name = field.serialized_name,
-                                field_name = field.name.as_ref()
-                            )?;
-                        }
+                        writeln!(f, "{indent}{}", PutField(field))?;
                     }
                 }
                 writeln!(f, "{indent}}}")?;
@@ -142,6 +132,61 @@ pub struct ObjectField {
     pub serialized_name: String,
     pub value_type: CompositeType,
     pub description: Option<Comment>,
+    /// 값이 비어있을 때 직렬화 결과에서 제외할지 여부
+    pub hide_if_empty: bool,
+    pub deprecated: bool,
+    /// 생성자 인자의 기본값으로 사용할 식
+    pub default_value: Option<String>,
+}
+
+impl ObjectField {
+    /// 생성자 인자의 기본값 (지정된 기본값이 없으면 optional 필드만 ` = null`)
+    pub(super) fn default_argument(&self) -> String {
+        match (&self.default_value, self.value_type.is_required) {
+            (Some(default_value), _) => format!(" = {default_value}"),
+            (None, true) => String::new(),
+            (None, false) => " = null".to_string(),
+        }
+    }
+}
+
+/// `toJson`의 `buildMap` 블록에서 필드 하나를 추가하는 구문
+pub(super) struct PutField<'a>(pub &'a ObjectField);
+
+impl fmt::Display for PutField<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let field = self.0;
+        let name = field.name.as_ref();
+        let serialized_name = &field.serialized_name;
+        let to_json = ToJson {
+            name,
+            is_list: field.value_type.is_list,
+            scalar: &field.value_type.scalar,
+        };
+        match (field.hide_if_empty, field.value_type.is_required) {
+            (false, true) => write!(f, "put(\"{serialized_name}\", {to_json})"),
+            (false, false) => write!(f, "{name}?.let {{ put(\"{serialized_name}\", {to_json}) }}"),
+            (true, true) if field.value_type.is_list => {
+                write!(
+                    f,
+                    "if ({name}.isNotEmpty()) put(\"{serialized_name}\", {to_json})"
+                )
+            }
+            (true, false) if field.value_type.is_list => {
+                write!(
+                    f,
+                    "if (!{name}.isNullOrEmpty()) put(\"{serialized_name}\", {to_json})"
+                )
+            }
+            (true, is_required) => {
+                let nullable = if is_required { "" } else { "?" };
+                write!(
+                    f,
+                    "{name}{nullable}.toJson(){nullable}.takeIf {{ it.isNotEmpty() }}?.let {{ put(\"{serialized_name}\", it) }}"
+                )
+            }
+        }
+    }
 }
 
 struct ToJson<'a> {
@@ -153,17 +198,31 @@ struct ToJson<'a> {
 impl fmt::Display for ToJson<'_> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let name = self.name;
-        match self.scalar {
-            ScalarType::Long | ScalarType::Boolean | ScalarType::Json | ScalarType::String => {
-                write!(f, "{name}")
-            }
-            ScalarType::TypeReference(_) => {
-                if self.is_list {
-                    write!(f, "{name}.map {{ it.toJson() }}")
-                } else {
-                    write!(f, "{name}.toJson()")
-                }
-            }
+        let conversion = if self.is_list {
+            to_json_conversion(self.scalar, "it").map(|it| format!("{name}.map {{ {it} }}"))
+        } else {
+            to_json_conversion(self.scalar, name)
+        };
+        write!(f, "{}", conversion.as_deref().unwrap_or(name))
+    }
+}
+
+/// 값을 JSON으로 직렬화할 수 있는 값으로 변환하는 식 (변환이 필요 없으면 `None`)
+pub(super) fn to_json_conversion(scalar: &ScalarType, value: &str) -> Option<String> {
+    match scalar {
+        ScalarType::Long
+        | ScalarType::Double
+        | ScalarType::Boolean
+        | ScalarType::Json
+        | ScalarType::String
+        | ScalarType::Exception => None,
+        ScalarType::Decimal => Some(format!("{value}.toPlainString()")),
+        ScalarType::TypeReference(_) => Some(format!("{value}.toJson()")),
+        ScalarType::Map(values) => {
+            to_json_conversion(values, "it.value").map(|it| format!("{value}.mapValues {{ {it} }}"))
+        }
+        ScalarType::List(items) => {
+            to_json_conversion(items, "it").map(|it| format!("{value}.map {{ {it} }}"))
         }
     }
 }
@@ -171,30 +230,32 @@ impl fmt::Display for ToJson<'_> {
 impl fmt::Display for ObjectField {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let nullable = if self.value_type.is_required { "" } else { "?" };
-        let default_value = if self.value_type.is_required {
-            ""
-        } else {
-            " = null"
-        };
+        let default_value = self.default_argument();
         let field_type = if self.value_type.is_list {
             format!("List<{}>", self.value_type.scalar.to_identifier())
         } else {
-            self.value_type.scalar.to_identifier().to_string()
+            self.value_type.scalar.to_identifier()
+        };
+
+        let annotation = if self.deprecated {
+            format!("{} ", DeprecatedAnnotation(None))
+        } else {
+            String::new()
         };
 
         // Add @RawValue annotation for JSON fields
-        match &self.value_type.scalar {
+        match self.value_type.scalar.value_scalar() {
             ScalarType::Json => {
                 write!(
                     f,
-                    "val {name}: @RawValue {field_type}{nullable}{default_value}",
+                    "{annotation}val {name}: @RawValue {field_type}{nullable}{default_value}",
                     name = self.name.as_ref()
                 )
             }
             _ => {
                 write!(
                     f,
-                    "val {name}: {field_type}{nullable}{default_value}",
+                    "{annotation}val {name}: {field_type}{nullable}{default_value}",
                     name = self.name.as_ref()
                 )
             }
@@ -224,6 +285,7 @@ mod tests {
             fields: vec![],
             is_one_of: false,
             union_parents: vec![],
+            deprecated: false,
         };
         assert_eq!(
             object.to_string(),
@@ -253,6 +315,9 @@ class IssueBillingKeyRequestUnionPaypal : Parcelable {
                         is_required: false,
                     },
                     description: None,
+                    hide_if_empty: false,
+                    deprecated: false,
+                    default_value: None,
                 },
                 ObjectField {
                     name: Identifier::try_from("addressLine1").unwrap(),
@@ -263,6 +328,9 @@ class IssueBillingKeyRequestUnionPaypal : Parcelable {
                         is_required: true,
                     },
                     description: Some(Comment::try_from("**일반주소**").unwrap()),
+                    hide_if_empty: false,
+                    deprecated: false,
+                    default_value: None,
                 },
                 ObjectField {
                     name: Identifier::try_from("addressLine2").unwrap(),
@@ -273,6 +341,9 @@ class IssueBillingKeyRequestUnionPaypal : Parcelable {
                         is_required: true,
                     },
                     description: Some(Comment::try_from("**상세주소**").unwrap()),
+                    hide_if_empty: false,
+                    deprecated: false,
+                    default_value: None,
                 },
                 ObjectField {
                     name: Identifier::try_from("city").unwrap(),
@@ -283,6 +354,9 @@ class IssueBillingKeyRequestUnionPaypal : Parcelable {
                         is_required: false,
                     },
                     description: Some(Comment::try_from("**도시**").unwrap()),
+                    hide_if_empty: false,
+                    deprecated: false,
+                    default_value: None,
                 },
                 ObjectField {
                     name: Identifier::try_from("province").unwrap(),
@@ -293,10 +367,14 @@ class IssueBillingKeyRequestUnionPaypal : Parcelable {
                         is_required: false,
                     },
                     description: Some(Comment::try_from("**주, 도, 시**").unwrap()),
+                    hide_if_empty: false,
+                    deprecated: false,
+                    default_value: None,
                 },
             ],
             is_one_of: false,
             union_parents: vec![],
+            deprecated: false,
         };
         assert_eq!(
             object.to_string(),
@@ -353,6 +431,9 @@ data class Address(
                         Comment::try_from("**구매자가 선택할 수 없도록 고정된 할부 개월수**")
                             .unwrap(),
                     ),
+                    hide_if_empty: false,
+                    deprecated: false,
+                    default_value: None,
                 },
                 ObjectField {
                     name: Identifier::try_from("availableMonthList").unwrap(),
@@ -366,10 +447,14 @@ data class Address(
                         Comment::try_from("**구매자가 선택할 수 있는 할부 개월수 리스트**")
                             .unwrap(),
                     ),
+                    hide_if_empty: false,
+                    deprecated: false,
+                    default_value: None,
                 },
             ],
             is_one_of: true,
             union_parents: vec![],
+            deprecated: false,
         };
         assert_eq!(
             object.to_string(),
@@ -413,6 +498,9 @@ sealed interface MonthOption : Parcelable {
                         is_required: true,
                     },
                     description: None,
+                    hide_if_empty: false,
+                    deprecated: false,
+                    default_value: None,
                 },
                 ObjectField {
                     name: Identifier::try_from("metadata").unwrap(),
@@ -423,6 +511,9 @@ sealed interface MonthOption : Parcelable {
                         is_required: true,
                     },
                     description: Some(Comment::try_from("**추가 메타데이터**").unwrap()),
+                    hide_if_empty: false,
+                    deprecated: false,
+                    default_value: None,
                 },
                 ObjectField {
                     name: Identifier::try_from("tags").unwrap(),
@@ -433,10 +524,14 @@ sealed interface MonthOption : Parcelable {
                         is_required: false,
                     },
                     description: None,
+                    hide_if_empty: false,
+                    deprecated: false,
+                    default_value: None,
                 },
             ],
             is_one_of: false,
             union_parents: vec![],
+            deprecated: false,
         };
         assert_eq!(
             object.to_string(),
@@ -458,6 +553,144 @@ data class CustomData(
         tags?.let { put("tags", tags) }
     }
 }
+"#
+        );
+    }
+
+    #[test]
+    fn object_with_hide_if_empty_fields() {
+        let field =
+            |name: &str, scalar: ScalarType, is_list: bool, is_required: bool| ObjectField {
+                name: Identifier::try_from(name).unwrap(),
+                serialized_name: name.to_string(),
+                value_type: CompositeType {
+                    scalar,
+                    is_list,
+                    is_required,
+                },
+                description: None,
+                hide_if_empty: true,
+                deprecated: false,
+                default_value: None,
+            };
+        let bypass = || {
+            ScalarType::TypeReference(TypeReference {
+                path: "entity".to_string(),
+                name: Identifier::try_from("Bypass").unwrap(),
+            })
+        };
+        let object = Object {
+            name: Identifier::try_from("Request").unwrap(),
+            description: None,
+            fields: vec![
+                field("products", ScalarType::String, true, true),
+                field("tags", ScalarType::String, true, false),
+                field("bypass", bypass(), false, true),
+                field("extra", bypass(), false, false),
+            ],
+            is_one_of: false,
+            union_parents: vec![],
+            deprecated: false,
+        };
+        assert_eq!(
+            object.to_string(),
+            r#"@Parcelize
+data class Request(
+    val products: List<String>,
+    val tags: List<String>? = null,
+    val bypass: Bypass,
+    val extra: Bypass? = null
+) : Parcelable {
+    fun toJson(): Map<String, Any> = buildMap {
+        if (products.isNotEmpty()) put("products", products)
+        if (!tags.isNullOrEmpty()) put("tags", tags)
+        bypass.toJson().takeIf { it.isNotEmpty() }?.let { put("bypass", it) }
+        extra?.toJson()?.takeIf { it.isNotEmpty() }?.let { put("extra", it) }
+    }
+}
+"#
+        );
+    }
+
+    #[test]
+    fn deprecated_object() {
+        let object = Object {
+            name: Identifier::try_from("Customer").unwrap(),
+            description: None,
+            fields: vec![ObjectField {
+                name: Identifier::try_from("birthYear").unwrap(),
+                serialized_name: "birthYear".to_string(),
+                value_type: CompositeType {
+                    scalar: ScalarType::String,
+                    is_list: false,
+                    is_required: false,
+                },
+                description: None,
+                hide_if_empty: false,
+                deprecated: true,
+                default_value: None,
+            }],
+            is_one_of: false,
+            union_parents: vec![],
+            deprecated: true,
+        };
+        assert_eq!(
+            object.to_string(),
+            r#"@Deprecated("This API is deprecated and will be removed in a future version.")
+@Parcelize
+data class Customer(
+    @Deprecated("This API is deprecated and will be removed in a future version.") val birthYear: String? = null
+) : Parcelable {
+    fun toJson(): Map<String, Any> = buildMap {
+        birthYear?.let { put("birthYear", birthYear) }
+    }
+}
+"#
+        );
+    }
+
+    #[test]
+    fn object_with_default_values() {
+        let field = |name: &str, scalar: ScalarType, default_value: &str| ObjectField {
+            name: Identifier::try_from(name).unwrap(),
+            serialized_name: name.to_string(),
+            value_type: CompositeType {
+                scalar,
+                is_list: false,
+                is_required: false,
+            },
+            description: None,
+            hide_if_empty: false,
+            deprecated: false,
+            default_value: Some(default_value.to_string()),
+        };
+        let locale = ScalarType::TypeReference(TypeReference {
+            path: "entity".to_string(),
+            name: Identifier::try_from("Locale").unwrap(),
+        });
+        let object = Object {
+            name: Identifier::try_from("Options").unwrap(),
+            description: None,
+            fields: vec![
+                field("locale", locale, "Locale.KO_KR"),
+                field("isTestChannel", ScalarType::Boolean, "false"),
+            ],
+            is_one_of: false,
+            union_parents: vec![],
+            deprecated: false,
+        };
+        assert_eq!(
+            object.to_string(),
+            r#"@Parcelize
+data class Options(
+    val locale: Locale? = Locale.KO_KR,
+    val isTestChannel: Boolean? = false
+) : Parcelable {
+    fun toJson(): Map<String, Any> = buildMap {
+        locale?.let { put("locale", locale.toJson()) }
+        isTestChannel?.let { put("isTestChannel", isTestChannel) }
+    }
+}
 "#
         );
     }
@@ -2,7 +2,10 @@ use std::fmt;
 
 use crate::ast::Indent;
 
-use super::{Comment, Identifier, TypeReference};
+use super::{
+    Comment, DeprecatedAnnotation, Identifier, ScalarType, TypeReference,
+    object::to_json_conversion, string_literal,
+};
 
 #[derive(Debug, Clone)]
 pub enum UnionParent {
@@ -17,15 +20,26 @@ pub struct Union {
     pub name: Identifier,
     pub description: Option<Comment>,
     pub variants: Vec<UnionVariant>,
+    pub deprecated: bool,
 }
 
 #[derive(Debug, Clone)]
 pub struct UnionVariant {
     pub name: Identifier,
-    pub type_name: TypeReference,
+    pub value_type: UnionVariantType,
     pub description: Option<Comment>,
 }
 
+#[derive(Debug, Clone)]
+pub enum UnionVariantType {
+    /// 리소스로 생성된 타입
+    TypeReference(TypeReference),
+    /// 원시 타입의 값
+    Scalar(ScalarType),
+    /// 값이 고정된 문자열 리터럴
+    Literal(String),
+}
+
 impl fmt::Display for Union {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         // Comments
@@ -36,13 +50,18 @@ impl fmt::Display for Union {
             }
             writeln!(f, " */")?;
         }
+        if self.deprecated {
+            writeln!(f, "{}", DeprecatedAnnotation(None))?;
+        }
 
         for variant in self.variants.iter() {
-            writeln!(
-                f,
-                "private typealias _{type_name} = {type_name}",
-                type_name = variant.type_name.name.as_ref()
-            )?;
+            if let UnionVariantType::TypeReference(type_name) = &variant.value_type {
+                writeln!(
+                    f,
+                    "private typealias _{type_name} = {type_name}",
+                    type_name = type_name.name.as_ref()
+                )?;
+            }
         }
         writeln!(f)?;
 
@@ -66,13 +85,30 @@ impl fmt::Display for Union {
                     writeln!(f, "{indent} */")?;
                 }
                 writeln!(f, "{indent}@Parcelize")?;
-                writeln!(
-                    f,
-                    "{indent}data class {variant_name}(val value: _{type_name}) : {name}()",
-                    variant_name = capitalize_first(variant.name.as_ref()),
-                    type_name = variant.type_name.name.as_ref(),
-                    name = self.name.as_ref(),
-                )?;
+                let variant_name = capitalize_first(variant.name.as_ref());
+                let name = self.name.as_ref();
+                match &variant.value_type {
+                    UnionVariantType::TypeReference(type_name) => writeln!(
+                        f,
+                        "{indent}data class {variant_name}(val value: _{type_name}) : {name}()",
+                        type_name = type_name.name.as_ref(),
+                    )?,
+                    UnionVariantType::Scalar(scalar) => {
+                        // JSON 값은 Parcelable이 아니므로 object 필드와 같이 @RawValue를 붙임
+                        let raw_value = match scalar.value_scalar() {
+                            ScalarType::Json => "@RawValue ",
+                            _ => "",
+                        };
+                        writeln!(
+                            f,
+                            "{indent}data class {variant_name}(val value: {raw_value}{type_name}) : {name}()",
+                            type_name = scalar.to_identifier(),
+                        )?
+                    }
+                    UnionVariantType::Literal(_) => {
+                        writeln!(f, "{indent}object {variant_name} : {name}()")?
+                    }
+                }
             }
 
             writeln!(f)?;
@@ -82,9 +118,16 @@ impl fmt::Display for Union {
             {
                 let indent = Indent(2);
                 for variant in self.variants.iter() {
+                    let value = match &variant.value_type {
+                        UnionVariantType::TypeReference(_) => "value.toJson()".to_string(),
+                        UnionVariantType::Scalar(scalar) => {
+                            to_json_conversion(scalar, "value").unwrap_or("value".to_string())
+                        }
+                        UnionVariantType::Literal(value) => string_literal(value),
+                    };
                     writeln!(
                         f,
-                        "{indent}is {variant_name} -> value.toJson()",
+                        "{indent}is {variant_name} -> {value}",
                         variant_name = capitalize_first(variant.name.as_ref())
                     )?;
                 }
@@ -115,21 +158,22 @@ mod tests {
             variants: vec![
                 UnionVariant {
                     name: Identifier::try_from("paymentUiType").unwrap(),
-                    type_name: TypeReference {
+                    value_type: UnionVariantType::TypeReference(TypeReference {
                         name: Identifier::try_from("PaymentUIType").unwrap(),
                         path: "".into(),
-                    },
+                    }),
                     description: None,
                 },
                 UnionVariant {
                     name: Identifier::try_from("issueBillingKeyUiType").unwrap(),
-                    type_name: TypeReference {
+                    value_type: UnionVariantType::TypeReference(TypeReference {
                         name: Identifier::try_from("IssueBillingKeyUIType").unwrap(),
                         path: "".into(),
-                    },
+                    }),
                     description: None,
                 },
             ],
+            deprecated: false,
         };
         assert_eq!(
             union.to_string(),
@@ -148,6 +192,60 @@ sealed class LoadableUIType : Parcelable {
         is IssueBillingKeyUiType -> value.toJson()
     }
 }
+"#
+        );
+    }
+
+    #[test]
+    fn union_with_literal_and_scalar_variants() {
+        let union = Union {
+            name: Identifier::try_from("CardInstallment").unwrap(),
+            description: None,
+            variants: vec![
+                UnionVariant {
+                    name: Identifier::try_from("union0").unwrap(),
+                    value_type: UnionVariantType::Literal("NONE".to_string()),
+                    description: None,
+                },
+                UnionVariant {
+                    name: Identifier::try_from("union1").unwrap(),
+                    value_type: UnionVariantType::Scalar(ScalarType::Long),
+                    description: None,
+                },
+                UnionVariant {
+                    name: Identifier::try_from("union2").unwrap(),
+                    value_type: UnionVariantType::Scalar(ScalarType::Decimal),
+                    description: None,
+                },
+                UnionVariant {
+                    name: Identifier::try_from("union3").unwrap(),
+                    value_type: UnionVariantType::Scalar(ScalarType::Json),
+                    description: None,
+                },
+            ],
+            deprecated: false,
+        };
+        assert_eq!(
+            union.to_string(),
+            r#"
+@Parcelize
+sealed class CardInstallment : Parcelable {
+    @Parcelize
+    object Union0 : CardInstallment()
+    @Parcelize
+    data class Union1(val value: Long) : CardInstallment()
+    @Parcelize
+    data class Union2(val value: BigDecimal) : CardInstallment()
+    @Parcelize
+    data class Union3(val value: @RawValue Map<String, Any?>) : CardInstallment()
+
+    fun toJson(): Any = when (this) {
+        is Union0 -> "NONE"
+        is Union1 -> value
+        is Union2 -> value.toPlainString()
+        is Union3 -> value
+    }
+}
 "#
         );
     }
@@ -1,10 +1,15 @@
 use std::{collections::HashMap, path::Path};
 
 use ast::{
-    Comment, CompositeType, Enum, EnumVariant, Identifier, Intersection, IntersectionConstituent,
-    Object, ObjectField, ScalarType, TypeReference, Union, UnionParent, UnionVariant,
+    BASE_ERROR_NAME, Callback, CallbackParameter, Callbacks, Comment, CompositeType, Enum,
+    EnumVariant, ErrorClass, Identifier, Intersection, IntersectionConstituent, Method,
+    MethodFacade, Object, ObjectField, ScalarType, TypeReference, Union, UnionParent, UnionVariant,
+    UnionVariantType, string_literal,
+};
+use client_sdk_schema::{
+    MOBILE_RESOURCE_ROOTS, Parameter, ParameterExt, ParameterType, RESOURCE_INDEX, Resource,
+    ResourceRef,
 };
-use client_sdk_schema::{Parameter, ParameterType, RESOURCE_INDEX, Resource, ResourceRef};
 use client_sdk_utils::write_generated_file;
 use convert_case::{Case, Casing};
 
@@ -15,6 +20,7 @@ enum Entity {
     Enum(Enum),
     Union(Union),
     Intersection(Intersection),
+    Error(ErrorClass),
 }
 
 struct ResourceProcessor {
@@ -76,11 +82,37 @@ impl ResourceProcessor {
         format!("{base_package}.{package_path}")
     }
 
+    /// 배열의 항목이나 맵의 값으로 쓰이는 타입
+    fn build_element_scalar(parameter: &Parameter) -> ScalarType {
+        match &parameter.r#type {
+            ParameterType::String { .. } | ParameterType::StringLiteral { .. } => {
+                ScalarType::String
+            }
+            ParameterType::Integer => ScalarType::Long,
+            ParameterType::Number { .. } => ScalarType::Double,
+            ParameterType::Decimal { .. } => ScalarType::Decimal,
+            ParameterType::Boolean => ScalarType::Boolean,
+            ParameterType::ResourceRef(resource_ref) => {
+                ScalarType::TypeReference(Self::resource_ref_to_type_reference(resource_ref))
+            }
+            ParameterType::Json => ScalarType::Json,
+            ParameterType::Enum { .. } => ScalarType::String,
+            ParameterType::Array { items, .. } => {
+                ScalarType::List(Box::new(Self::build_element_scalar(items)))
+            }
+            ParameterType::Map { values } => {
+                ScalarType::Map(Box::new(Self::build_element_scalar(values)))
+            }
+            _ => unreachable!(),
+        }
+    }
+
     fn build_field(name: &str, parameter: &Parameter) -> ObjectField {
         let field_name: Identifier = name.to_case(Case::Camel).try_into().unwrap();
         let is_required = !parameter.optional;
+        let deprecated = parameter.deprecated();
         let value_type = match &parameter.r#type {
-            ParameterType::String | ParameterType::StringLiteral { .. } => CompositeType {
+            ParameterType::String { .. } | ParameterType::StringLiteral { .. } => CompositeType {
                 scalar: ScalarType::String,
                 is_list: false,
                 is_required,
@@ -90,6 +122,16 @@ impl ResourceProcessor {
                 is_list: false,
                 is_required,
             },
+            ParameterType::Number { .. } => CompositeType {
+                scalar: ScalarType::Double,
+                is_list: false,
+                is_required,
+            },
+            ParameterType::Decimal { .. } => CompositeType {
+                scalar: ScalarType::Decimal,
+                is_list: false,
+                is_required,
+            },
             ParameterType::Boolean => CompositeType {
                 scalar: ScalarType::Boolean,
                 is_list: false,
@@ -100,6 +142,11 @@ impl ResourceProcessor {
                 is_list: false,
                 is_required,
             },
+            ParameterType::Error { .. } => CompositeType {
+                scalar: ScalarType::Exception,
+                is_list: false,
+                is_required,
+            },
             ParameterType::Enum { .. } => CompositeType {
                 scalar: ScalarType::String,
                 is_list: false,
@@ -108,27 +155,18 @@ impl ResourceProcessor {
             ParameterType::Array {
                 items,
                 hide_if_empty: _,
-            } => {
-                let scalar = match &items.r#type {
-                    ParameterType::String | ParameterType::StringLiteral { .. } => {
-                        ScalarType::String
-                    }
-                    ParameterType::Integer => ScalarType::Long,
-                    ParameterType::Boolean => ScalarType::Boolean,
-                    ParameterType::ResourceRef(resource_ref) => ScalarType::TypeReference(
-                        Self::resource_ref_to_type_reference(resource_ref),
-                    ),
-                    ParameterType::Json => ScalarType::Json,
-                    ParameterType::Enum { .. } => ScalarType::String,
-                    _ => unreachable!(),
-                };
-                CompositeType {
-                    scalar,
-                    is_list: true,
-                    is_required,
-                }
-            }
+            } => CompositeType {
+                scalar: Self::build_element_scalar(items),
+                is_list: true,
+                is_required,
+            },
+            ParameterType::Map { values } => CompositeType {
+                scalar: ScalarType::Map(Box::new(Self::build_element_scalar(values))),
+                is_list: false,
+                is_required,
+            },
             ParameterType::ResourceRef(resource_ref) => {
+                let field_parameter = parameter;
                 return RESOURCE_INDEX.with(|index| {
                     let mut resource_ref = resource_ref;
                     loop {
@@ -139,7 +177,8 @@ impl ResourceProcessor {
                             | ParameterType::Enum { .. }
                             | ParameterType::Union { .. }
                             | ParameterType::OneOf { .. }
-                            | ParameterType::Intersection { .. } => {
+                            | ParameterType::Intersection { .. }
+                            | ParameterType::Error { .. } => {
                                 let value_type = CompositeType {
                                     scalar: ScalarType::TypeReference(
                                         Self::resource_ref_to_type_reference(resource_ref),
@@ -147,6 +186,8 @@ impl ResourceProcessor {
                                     is_list: false,
                                     is_required,
                                 };
+                                let default_value =
+                                    Self::build_default_value(field_parameter, &value_type);
                                 break ObjectField {
                                     name: field_name,
                                     serialized_name: name.to_string(),
@@ -155,6 +196,22 @@ impl ResourceProcessor {
                                         .description
                                         .clone()
                                         .map(|d| Comment::try_from(d).unwrap()),
+                                    // 직렬화 결과가 Map인 타입만 비어있는지 확인할 수 있음
+                                    hide_if_empty: matches!(
+                                        parameter.r#type,
+                                        ParameterType::Object {
+                                            hide_if_empty: true,
+                                            ..
+                                        } | ParameterType::OneOf {
+                                            hide_if_empty: true,
+                                            ..
+                                        } | ParameterType::Intersection {
+                                            hide_if_empty: true,
+                                            ..
+                                        }
+                                    ),
+                                    deprecated,
+                                    default_value,
                                 };
                             }
                             ParameterType::ResourceRef(r) => {
@@ -163,6 +220,13 @@ impl ResourceProcessor {
                             _ => {
                                 let mut field = Self::build_field(name, parameter);
                                 field.value_type.is_required = is_required;
+                                field.deprecated = deprecated;
+                                if field_parameter.default.is_some() {
+                                    field.default_value = Self::build_default_value(
+                                        field_parameter,
+                                        &field.value_type,
+                                    );
+                                }
                                 break field;
                             }
                         }
@@ -171,11 +235,61 @@ impl ResourceProcessor {
             }
             _ => unreachable!("{:#?}", parameter.r#type),
         };
+        let default_value = Self::build_default_value(parameter, &value_type);
         ObjectField {
             name: field_name,
             serialized_name: name.to_string(),
             value_type,
             description: Self::build_field_description(parameter),
+            hide_if_empty: matches!(
+                parameter.r#type,
+                ParameterType::Array {
+                    hide_if_empty: true,
+                    ..
+                }
+            ),
+            deprecated,
+            default_value,
+        }
+    }
+
+    /// 파라미터의 기본값을 생성자의 기본 인자로 사용할 Kotlin 식으로 변환합니다.
+    fn build_default_value(parameter: &Parameter, value_type: &CompositeType) -> Option<String> {
+        let default = parameter.default.as_deref()?;
+        if value_type.is_list {
+            return None;
+        }
+        match &value_type.scalar {
+            ScalarType::String => default.as_str().map(string_literal),
+            ScalarType::Long => default.as_i64().map(|value| format!("{value}L")),
+            ScalarType::Double => default.as_f64().map(|value| format!("{value:?}")),
+            ScalarType::Decimal => default
+                .as_str()
+                .map(|value| format!("BigDecimal(\"{value}\")")),
+            ScalarType::Boolean => default.as_bool().map(|value| value.to_string()),
+            ScalarType::TypeReference(TypeReference { name, .. }) => {
+                let variant = Self::enum_variant_name(&parameter.r#type, default.as_str()?)?;
+                Some(format!("{}.{}", name.as_ref(), variant.as_ref()))
+            }
+            ScalarType::Json | ScalarType::Exception | ScalarType::Map(_) | ScalarType::List(_) => {
+                None
+            }
+        }
+    }
+
+    /// 기본값에 해당하는 enum variant의 이름을 찾습니다.
+    fn enum_variant_name(r#type: &ParameterType, value: &str) -> Option<Identifier> {
+        match r#type {
+            ParameterType::Enum { variants, .. } => {
+                let variant = variants.get(value)?;
+                let name = variant.alias.as_deref().unwrap_or(value);
+                Identifier::try_from(name).ok()
+            }
+            ParameterType::ResourceRef(resource_ref) => RESOURCE_INDEX.with(|index| {
+                let parameter = index.get(resource_ref.resource_ref())?;
+                Self::enum_variant_name(&parameter.r#type, value)
+            }),
+            _ => None,
         }
     }
 
@@ -203,6 +317,15 @@ impl ResourceProcessor {
             }
         }
 
+        let constraints = parameter.r#type.constraints();
+        if !constraints.is_empty() {
+            let constraint_lines: Vec<String> = constraints
+                .iter()
+                .map(|constraint| format!("- {constraint}"))
+                .collect();
+            desc_parts.push(constraint_lines.join("\n"));
+        }
+
         if desc_parts.is_empty() {
             None
         } else {
@@ -248,8 +371,11 @@ impl ResourceProcessor {
                     name: parent.name.clone(),
                 };
                 for variant in parent.variants.iter() {
+                    let UnionVariantType::TypeReference(type_name) = &variant.value_type else {
+                        continue;
+                    };
                     union_parents
-                        .entry(variant.type_name.path.clone())
+                        .entry(type_name.path.clone())
                         .or_insert(vec![])
                         .push(UnionParent::Union {
                             parent: parent_ref.clone(),
@@ -265,6 +391,8 @@ impl ResourceProcessor {
                 | Entity::Enum(Enum { union_parents, .. }) => {
                     *union_parents = parents;
                 }
+                // 에러 클래스는 Parcelable이 아니므로 union 변환 함수를 생성하지 않음
+                Entity::Error(_) => {}
                 _ => unreachable!(),
             }
         }
@@ -290,6 +418,7 @@ impl ResourceProcessor {
                 fields: Self::build_field_list(properties.iter()),
                 is_one_of: false,
                 union_parents: vec![],
+                deprecated: parameter.deprecated,
             })),
             ParameterType::EmptyObject => Some(Entity::Object(Object {
                 name: name.clone(),
@@ -300,6 +429,7 @@ impl ResourceProcessor {
                 fields: vec![],
                 is_one_of: false,
                 union_parents: vec![],
+                deprecated: parameter.deprecated,
             })),
             ParameterType::Enum { variants, .. } => Some(Entity::Enum(Enum {
                 name: name.clone(),
@@ -320,9 +450,12 @@ impl ResourceProcessor {
                             .description
                             .clone()
                             .map(|d| Comment::try_from(d).unwrap()),
+                        deprecated: variant.deprecated,
+                        replacement: variant.replacement.clone(),
                     })
                     .collect(),
                 union_parents: vec![],
+                deprecated: parameter.deprecated,
             })),
             ParameterType::OneOf {
                 properties,
@@ -336,6 +469,7 @@ impl ResourceProcessor {
                 fields: Self::build_field_list(properties.iter()),
                 is_one_of: true,
                 union_parents: vec![],
+                deprecated: parameter.deprecated,
             })),
             ParameterType::Union {
                 types,
@@ -348,21 +482,40 @@ impl ResourceProcessor {
                     .map(|d| Comment::try_from(d).unwrap()),
                 variants: types
                     .iter()
-                    .map(|parameter| match &parameter.r#type {
-                        ParameterType::ResourceRef(resource_ref) => {
-                            let type_reference = Self::resource_ref_to_type_reference(resource_ref);
-                            UnionVariant {
-                                name: type_reference.name.as_ref().try_into().unwrap(),
-                                description: parameter
-                                    .description
-                                    .clone()
-                                    .map(|d| Comment::try_from(d).unwrap()),
-                                type_name: type_reference,
+                    .enumerate()
+                    .map(|(i, parameter)| {
+                        let description = parameter
+                            .description
+                            .clone()
+                            .map(|d| Comment::try_from(d).unwrap());
+                        // 리소스가 아닌 멤버는 TypeScript와 같이 순서로 이름을 지음
+                        let name = Identifier::try_from(format!("union{i}")).unwrap();
+                        match &parameter.r#type {
+                            ParameterType::ResourceRef(resource_ref) => {
+                                let type_reference =
+                                    Self::resource_ref_to_type_reference(resource_ref);
+                                UnionVariant {
+                                    name: type_reference.name.as_ref().try_into().unwrap(),
+                                    description,
+                                    value_type: UnionVariantType::TypeReference(type_reference),
+                                }
                             }
+                            ParameterType::StringLiteral { value } => UnionVariant {
+                                name,
+                                description,
+                                value_type: UnionVariantType::Literal(value.clone()),
+                            },
+                            _ => UnionVariant {
+                                name,
+                                description,
+                                value_type: UnionVariantType::Scalar(Self::build_element_scalar(
+                                    parameter,
+                                )),
+                            },
                         }
-                        _ => unreachable!(),
                     })
                     .collect(),
+                deprecated: parameter.deprecated,
             })),
             ParameterType::Intersection {
                 types,
@@ -408,12 +561,47 @@ impl ResourceProcessor {
                     constituents,
                     fields: all_fields,
                     union_parents: vec![],
+                    deprecated: parameter.deprecated,
                 }))
             }
+            ParameterType::Error {
+                transaction_type,
+                properties,
+            } => Some(Entity::Error(ErrorClass {
+                name: name.clone(),
+                description: parameter
+                    .description
+                    .clone()
+                    .map(|d| Comment::try_from(d).unwrap()),
+                transaction_type: transaction_type.clone(),
+                fields: Self::build_field_list(properties.iter()),
+            })),
             _ => None,
         }
     }
 
+    fn package_name(path: &str, import_base_path: &Path) -> String {
+        let base = import_base_path
+            .to_string_lossy()
+            .replace('/', ".")
+            .replace('-', "_");
+        let sub = Path::new(path)
+            .parent()
+            .map(|p| {
+                p.to_string_lossy()
+                    .split('/')
+                    .filter(|s| !s.is_empty())
+                    .collect::<Vec<_>>()
+                    .join(".")
+            })
+            .unwrap_or_default();
+        if sub.is_empty() {
+            base
+        } else {
+            format!("{base}.{sub}")
+        }
+    }
+
     fn generate_directory(
         self,
         file_base_path: impl AsRef<Path>,
@@ -449,7 +637,9 @@ impl ResourceProcessor {
             let content = match entity {
                 Entity::Object(object) => {
                     let fields_refs = object.fields.iter().flat_map(|field| {
-                        if let ScalarType::TypeReference(reference) = &field.value_type.scalar {
+                        if let ScalarType::TypeReference(reference) =
+                            field.value_type.scalar.value_scalar()
+                        {
                             Some(reference)
                         } else {
                             None
@@ -471,14 +661,20 @@ impl ResourceProcessor {
                     imports.push("kotlinx.parcelize.Parcelize".to_string());
 
                     // Add RawValue import if any field is JSON type
-                    let has_json_field = object
-                        .fields
-                        .iter()
-                        .any(|field| matches!(field.value_type.scalar, ScalarType::Json));
+                    let has_json_field = object.fields.iter().any(|field| {
+                        matches!(field.value_type.scalar.value_scalar(), ScalarType::Json)
+                    });
                     if has_json_field {
                         imports.push("kotlinx.parcelize.RawValue".to_string());
                     }
 
+                    let has_decimal_field = object.fields.iter().any(|field| {
+                        matches!(field.value_type.scalar.value_scalar(), ScalarType::Decimal)
+                    });
+                    if has_decimal_field {
+                        imports.push("java.math.BigDecimal".to_string());
+                    }
+
                     imports.sort();
                     imports.dedup();
 
@@ -562,10 +758,28 @@ impl ResourceProcessor {
                     content
                 }
                 Entity::Union(union) => {
-                    let variants_refs = union.variants.iter().map(|variant| &variant.type_name);
-                    let mut imports = variants_refs
-                        .map(|reference| {
-                            Self::type_reference_to_import_path(reference, import_base_path)
+                    let mut imports = union
+                        .variants
+                        .iter()
+                        .filter_map(|variant| {
+                            let reference = match &variant.value_type {
+                                UnionVariantType::TypeReference(reference) => reference,
+                                UnionVariantType::Scalar(scalar) => match scalar.value_scalar() {
+                                    ScalarType::TypeReference(reference) => reference,
+                                    ScalarType::Decimal => {
+                                        return Some("java.math.BigDecimal".to_string());
+                                    }
+                                    ScalarType::Json => {
+                                        return Some("kotlinx.parcelize.RawValue".to_string());
+                                    }
+                                    _ => return None,
+                                },
+                                UnionVariantType::Literal(_) => return None,
+                            };
+                            Some(Self::type_reference_to_import_path(
+                                reference,
+                                import_base_path,
+                            ))
                         })
                         .collect::<Vec<_>>();
 
@@ -622,7 +836,9 @@ impl ResourceProcessor {
 
                 Entity::Intersection(intersection) => {
                     let fields_refs = intersection.fields.iter().flat_map(|field| {
-                        if let ScalarType::TypeReference(reference) = &field.value_type.scalar {
+                        if let ScalarType::TypeReference(reference) =
+                            field.value_type.scalar.value_scalar()
+                        {
                             Some(reference)
                         } else {
                             None
@@ -644,14 +860,20 @@ impl ResourceProcessor {
                     imports.push("kotlinx.parcelize.Parcelize".to_string());
 
                     // Add RawValue import if any field is JSON type
-                    let has_json_field = intersection
-                        .fields
-                        .iter()
-                        .any(|field| matches!(field.value_type.scalar, ScalarType::Json));
+                    let has_json_field = intersection.fields.iter().any(|field| {
+                        matches!(field.value_type.scalar.value_scalar(), ScalarType::Json)
+                    });
                     if has_json_field {
                         imports.push("kotlinx.parcelize.RawValue".to_string());
                     }
 
+                    let has_decimal_field = intersection.fields.iter().any(|field| {
+                        matches!(field.value_type.scalar.value_scalar(), ScalarType::Decimal)
+                    });
+                    if has_decimal_field {
+                        imports.push("java.math.BigDecimal".to_string());
+                    }
+
                     imports.sort();
                     imports.dedup();
 
@@ -698,6 +920,46 @@ impl ResourceProcessor {
                     write!(content, "{intersection}").unwrap();
                     content
                 }
+                Entity::Error(error) => {
+                    let base_error = TypeReference {
+                        path: format!("exception/{BASE_ERROR_NAME}"),
+                        name: Identifier::try_from(BASE_ERROR_NAME).unwrap(),
+                    };
+                    let fields_refs = error.fields.iter().flat_map(|field| {
+                        if let ScalarType::TypeReference(reference) =
+                            field.value_type.scalar.value_scalar()
+                        {
+                            Some(reference)
+                        } else {
+                            None
+                        }
+                    });
+                    let mut imports = fields_refs
+                        .chain(std::iter::once(&base_error))
+                        .map(|reference| {
+                            Self::type_reference_to_import_path(reference, import_base_path)
+                        })
+                        .collect::<Vec<_>>();
+                    if error.fields.iter().any(|field| {
+                        matches!(field.value_type.scalar.value_scalar(), ScalarType::Decimal)
+                    }) {
+                        imports.push("java.math.BigDecimal".to_string());
+                    }
+                    imports.sort();
+                    imports.dedup();
+
+                    use std::fmt::Write;
+                    let mut content = String::new();
+                    let package_name = Self::package_name(&path, import_base_path);
+                    writeln!(&mut content, "package {package_name}").unwrap();
+                    writeln!(content).unwrap();
+                    for import in imports {
+                        writeln!(&mut content, "import {import}").unwrap();
+                    }
+                    writeln!(content).unwrap();
+                    write!(content, "{error}").unwrap();
+                    content
+                }
             };
             let mut file_path = file_base_path.join(path.to_case(Case::Camel));
             file_path.set_extension("kt");
@@ -717,7 +979,7 @@ pub fn generate_resources_module(
     };
     if let Resource::SubResources(subresources) = resource {
         for (key, value) in subresources.iter() {
-            if matches!(key.as_str(), "entity" | "request" | "response") {
+            if MOBILE_RESOURCE_ROOTS.contains(&key.as_str()) {
                 processor.process_resource(value, &mut vec![key.clone()]);
             }
         }
@@ -747,6 +1009,140 @@ pub fn generate_resources_module(
             }
         }
     }
+    let has_error = processor
+        .entities
+        .values()
+        .any(|entity| matches!(entity, Entity::Error(_)));
     processor.connect_union_parents();
-    processor.generate_directory(file_base_path, import_base_path);
+    processor.generate_directory(&file_base_path, &import_base_path);
+
+    // 모든 에러 클래스의 기본 클래스 생성
+    if has_error {
+        let path = format!("exception/{BASE_ERROR_NAME}");
+        let package_name = ResourceProcessor::package_name(&path, import_base_path.as_ref());
+        let content = format!(
+            r#"package {package_name}
+
+abstract class {BASE_ERROR_NAME}(message: String?) : Exception(message) {{
+    open val transactionType: String? = null
+}}
+"#
+        );
+        let mut file_path = file_base_path.as_ref().join(path.to_case(Case::Camel));
+        file_path.set_extension("kt");
+        std::fs::create_dir_all(file_path.parent().unwrap()).unwrap();
+        write_generated_file(file_path, content).unwrap();
+    }
+}
+
+fn build_method(name: &str, method: &client_sdk_schema::Method) -> Option<Method> {
+    if RESOURCE_INDEX
+        .with(|index| method.find_unsupported_mobile_parameter(index))
+        .is_some()
+    {
+        return None;
+    }
+
+    let callbacks = method.callbacks.as_ref().map(|callbacks| Callbacks {
+        name: Identifier::try_from(format!("{}Callbacks", name.to_case(Case::Pascal))).unwrap(),
+        callbacks: callbacks
+            .iter()
+            .map(|(callback_name, callback)| Callback {
+                name: Identifier::try_from(callback_name.as_str()).unwrap(),
+                description: callback
+                    .description
+                    .clone()
+                    .map(|d| Comment::try_from(d).unwrap()),
+                parameters: callback
+                    .input
+                    .iter()
+                    .map(|(parameter_name, parameter)| {
+                        let field = ResourceProcessor::build_field(parameter_name, parameter);
+                        CallbackParameter {
+                            name: field.name,
+                            value_type: field.value_type,
+                        }
+                    })
+                    .collect(),
+                deprecated: callback.deprecated,
+                replacement: callback.replacement.clone(),
+            })
+            .collect(),
+    });
+    Some(Method {
+        name: Identifier::try_from(name).unwrap(),
+        description: method
+            .description
+            .clone()
+            .map(|d| Comment::try_from(d).unwrap()),
+        input: ResourceProcessor::build_field("request", &method.input).value_type,
+        output: method
+            .output
+            .as_ref()
+            .map(|output| ResourceProcessor::build_field("response", output).value_type),
+        callbacks,
+        deprecated: method.deprecated,
+        replacement: method.replacement.clone(),
+    })
+}
+
+/// 스키마의 메소드 목록으로 `PortOne` interface를 생성합니다.
+///
+/// 모바일 SDK에서 생성되지 않는 타입을 사용하는 메소드는 제외되며, 스키마 검사에서 경고로 보고됩니다.
+pub fn generate_methods_module<'a>(
+    methods: impl IntoIterator<Item = (&'a String, &'a client_sdk_schema::Method)>,
+    file_base_path: impl AsRef<Path>,
+    import_base_path: impl AsRef<Path>,
+) {
+    let import_base_path = import_base_path.as_ref();
+    let facade = MethodFacade {
+        name: Identifier::try_from("PortOne").unwrap(),
+        methods: methods
+            .into_iter()
+            .filter_map(|(name, method)| build_method(name, method))
+            .collect(),
+    };
+
+    let callback_types = facade
+        .methods
+        .iter()
+        .filter_map(|method| method.callbacks.as_ref())
+        .flat_map(|callbacks| callbacks.callbacks.iter())
+        .flat_map(|callback| callback.parameters.iter())
+        .map(|parameter| &parameter.value_type);
+    let mut imports = facade
+        .methods
+        .iter()
+        .flat_map(|method| std::iter::once(&method.input).chain(method.output.as_ref()))
+        .chain(callback_types)
+        .filter_map(|value_type| match value_type.scalar.value_scalar() {
+            ScalarType::TypeReference(reference) => Some(
+                ResourceProcessor::type_reference_to_import_path(reference, import_base_path),
+            ),
+            ScalarType::Decimal => Some("java.math.BigDecimal".to_string()),
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+    imports.sort();
+    imports.dedup();
+
+    use std::fmt::Write;
+    let mut content = String::new();
+    let package_name = import_base_path
+        .to_string_lossy()
+        .replace('/', ".")
+        .replace('-', "_");
+    writeln!(&mut content, "package {package_name}").unwrap();
+    writeln!(content).unwrap();
+    if !imports.is_empty() {
+        for import in imports {
+            writeln!(&mut content, "import {import}").unwrap();
+        }
+        writeln!(content).unwrap();
+    }
+    write!(content, "{facade}").unwrap();
+
+    let file_path = file_base_path.as_ref().join("PortOne.kt");
+    std::fs::create_dir_all(file_path.parent().unwrap()).unwrap();
+    write_generated_file(file_path, content).unwrap();
 }
@@ -0,0 +1,1172 @@
+use std::{collections::HashMap, fmt};
+
+use indexmap::IndexMap;
+
+use crate::{Callback, Method, Parameter, ParameterType, Schema};
+
+/// 변경된 항목의 종류
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Element {
+    Resource,
+    Method,
+    Output,
+    Callback,
+    CallbackParameter,
+    Property,
+    Variant,
+    Member,
+}
+
+impl fmt::Display for Element {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Element::Resource => write!(f, "resource"),
+            Element::Method => write!(f, "method"),
+            Element::Output => write!(f, "method output"),
+            Element::Callback => write!(f, "callback"),
+            Element::CallbackParameter => write!(f, "callback parameter"),
+            Element::Property => write!(f, "property"),
+            Element::Variant => write!(f, "enum variant"),
+            Element::Member => write!(f, "member"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ChangeKind {
+    Added(Element),
+    Removed(Element),
+    /// optional에서 required로 변경
+    BecameRequired,
+    /// required에서 optional로 변경
+    BecameOptional,
+    TypeChanged {
+        from: String,
+        to: String,
+    },
+    Deprecated,
+    /// 기본값 변경 (값을 지정하지 않는 기존 코드의 동작이 바뀜)
+    DefaultChanged {
+        from: Option<String>,
+        to: Option<String>,
+    },
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Change {
+    /// 변경이 발생한 위치 (예: `#/resources/entity/Bank/properties/code`)
+    pub path: String,
+    pub kind: ChangeKind,
+    /// 기존 클라이언트 코드가 깨질 수 있는 변경인지 여부
+    pub breaking: bool,
+    pub direction: Direction,
+    /// 변경된 항목의 설명 (제거된 항목은 이전 스키마 기준)
+    pub description: Option<String>,
+}
+
+impl fmt::Display for ChangeKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ChangeKind::Added(element) => write!(f, "{element} added"),
+            ChangeKind::Removed(element) => write!(f, "{element} removed"),
+            ChangeKind::BecameRequired => write!(f, "became required"),
+            ChangeKind::BecameOptional => write!(f, "became optional"),
+            ChangeKind::TypeChanged { from, to } => write!(f, "type changed from {from} to {to}"),
+            ChangeKind::Deprecated => write!(f, "deprecated"),
+            ChangeKind::DefaultChanged { from, to } => {
+                let value = |value: &Option<String>| match value {
+                    Some(value) => format!("`{value}`"),
+                    None => "none".to_string(),
+                };
+                write!(f, "default changed from {} to {}", value(from), value(to))
+            }
+        }
+    }
+}
+
+impl fmt::Display for Change {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.path, self.kind)
+    }
+}
+
+/// 변경 사항에 따라 올려야 하는 버전
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Bump {
+    Patch,
+    Minor,
+    Major,
+}
+
+impl fmt::Display for Bump {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Bump::Patch => write!(f, "patch"),
+            Bump::Minor => write!(f, "minor"),
+            Bump::Major => write!(f, "major"),
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct SchemaDiff {
+    pub changes: Vec<Change>,
+}
+
+impl SchemaDiff {
+    pub fn has_breaking_changes(&self) -> bool {
+        self.changes.iter().any(|change| change.breaking)
+    }
+
+    pub fn suggested_bump(&self) -> Bump {
+        if self.has_breaking_changes() {
+            Bump::Major
+        } else if !self.changes.is_empty() {
+            Bump::Minor
+        } else {
+            Bump::Patch
+        }
+    }
+}
+
+/// 클라이언트 기준으로 값이 전달되는 방향
+///
+/// 메소드 입력은 클라이언트가 만드는 값이고, 메소드 출력과 콜백 파라미터는 클라이언트가 받는 값입니다.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Direction {
+    pub input: bool,
+    pub output: bool,
+}
+
+impl Direction {
+    const INPUT: Direction = Direction {
+        input: true,
+        output: false,
+    };
+    const OUTPUT: Direction = Direction {
+        input: false,
+        output: true,
+    };
+    const BOTH: Direction = Direction {
+        input: true,
+        output: true,
+    };
+
+    fn union(self, other: Direction) -> Direction {
+        Direction {
+            input: self.input || other.input,
+            output: self.output || other.output,
+        }
+    }
+}
+
+impl Schema {
+    /// `self`를 기준으로 `new`에서 달라진 리소스, 프로퍼티, enum variant, 메소드를 비교합니다.
+    pub fn diff(&self, new: &Schema) -> SchemaDiff {
+        let old_index = self.build_resource_index();
+        let new_index = new.build_resource_index();
+        // 양쪽 스키마에서의 사용처를 모두 고려
+        let mut usage = HashMap::new();
+        collect_usage(self, &old_index, &mut usage);
+        collect_usage(new, &new_index, &mut usage);
+
+        let mut differ = Differ {
+            changes: vec![],
+            usage,
+        };
+        differ.diff_resources(&old_index, &new_index);
+        differ.diff_methods(&self.methods, &new.methods);
+        SchemaDiff {
+            changes: differ.changes,
+        }
+    }
+}
+
+fn collect_usage(
+    schema: &Schema,
+    index: &IndexMap<String, Parameter>,
+    usage: &mut HashMap<String, Direction>,
+) {
+    for method in schema.methods.values() {
+        mark_usage(&method.input, Direction::INPUT, index, usage);
+        if let Some(output) = &method.output {
+            mark_usage(output, Direction::OUTPUT, index, usage);
+        }
+        for callback in method.callbacks.iter().flat_map(IndexMap::values) {
+            for parameter in callback.input.values() {
+                mark_usage(parameter, Direction::OUTPUT, index, usage);
+            }
+        }
+    }
+}
+
+fn mark_usage(
+    parameter: &Parameter,
+    direction: Direction,
+    index: &IndexMap<String, Parameter>,
+    usage: &mut HashMap<String, Direction>,
+) {
+    match &parameter.r#type {
+        ParameterType::ResourceRef(resource_ref) => {
+            let target = resource_ref.resource_ref();
+            let previous = usage.get(target).copied().unwrap_or_default();
+            let merged = previous.union(direction);
+            // 이미 같은 방향으로 표시된 리소스는 다시 방문하지 않으므로 순환 참조에서도 종료됩니다.
+            if merged != previous {
+                usage.insert(target.to_string(), merged);
+                if let Some(resource) = index.get(target) {
+                    mark_usage(resource, merged, index, usage);
+                }
+            }
+        }
+        ParameterType::Array { items, .. } | ParameterType::Map { values: items } => {
+            mark_usage(items, direction, index, usage)
+        }
+        ParameterType::Object { properties, .. }
+        | ParameterType::OneOf { properties, .. }
+        | ParameterType::Error { properties, .. } => {
+            for property in properties.values() {
+                mark_usage(property, direction, index, usage);
+            }
+        }
+        ParameterType::Union { types, .. } | ParameterType::Intersection { types, .. } => {
+            for member in types {
+                mark_usage(member, direction, index, usage);
+            }
+        }
+        _ => {}
+    }
+}
+
+fn type_name(r#type: &ParameterType) -> String {
+    match r#type {
+        ParameterType::String { .. } => {
+            let constraints = string_constraints_text(r#type);
+            if constraints.is_empty() {
+                "string".to_string()
+            } else {
+                format!("string ({constraints})")
+            }
+        }
+        ParameterType::StringLiteral { value } => format!("stringLiteral `{value}`"),
+        ParameterType::Integer => "integer".to_string(),
+        ParameterType::Number { minimum, maximum } => {
+            format!("number{}", range_text(*minimum, *maximum))
+        }
+        ParameterType::Decimal { minimum, maximum } => {
+            format!("decimal{}", range_text(*minimum, *maximum))
+        }
+        ParameterType::Boolean => "boolean".to_string(),
+        ParameterType::Array { .. } => "array".to_string(),
+        ParameterType::Map { .. } => "map".to_string(),
+        ParameterType::Object { .. } => "object".to_string(),
+        ParameterType::EmptyObject => "emptyObject".to_string(),
+        ParameterType::Enum { .. } => "enum".to_string(),
+        ParameterType::OneOf { .. } => "oneOf".to_string(),
+        ParameterType::Union { .. } => "union".to_string(),
+        ParameterType::Intersection { .. } => "intersection".to_string(),
+        ParameterType::ResourceRef(resource_ref) => {
+            format!("`#/resources/{}`", resource_ref.resource_ref())
+        }
+        ParameterType::Error { .. } => "error".to_string(),
+        ParameterType::Json => "json".to_string(),
+    }
+}
+
+fn range_text(minimum: Option<f64>, maximum: Option<f64>) -> String {
+    match (minimum, maximum) {
+        (None, None) => String::new(),
+        (Some(minimum), None) => format!(" (minimum {minimum})"),
+        (None, Some(maximum)) => format!(" (maximum {maximum})"),
+        (Some(minimum), Some(maximum)) => format!(" (minimum {minimum}, maximum {maximum})"),
+    }
+}
+
+fn string_constraints_text(r#type: &ParameterType) -> String {
+    let ParameterType::String {
+        pattern,
+        min_length,
+        max_length,
+        format,
+    } = r#type
+    else {
+        return String::new();
+    };
+    let mut constraints = vec![];
+    if let Some(format) = format {
+        constraints.push(format!("format {format}"));
+    }
+    if let Some(pattern) = pattern {
+        constraints.push(format!("pattern `{pattern}`"));
+    }
+    if let Some(min_length) = min_length {
+        constraints.push(format!("minLength {min_length}"));
+    }
+    if let Some(max_length) = max_length {
+        constraints.push(format!("maxLength {max_length}"));
+    }
+    constraints.join(", ")
+}
+
+/// 새 문자열 제약 조건이 기존에 허용되던 값을 거부할 수 있는지 확인합니다.
+///
+/// 패턴과 형식은 포함 관계를 알 수 없으므로 새로 지정되거나 바뀌면 좁아진 것으로 봅니다.
+fn is_string_narrowed(old: &ParameterType, new: &ParameterType) -> bool {
+    let (
+        ParameterType::String {
+            pattern: old_pattern,
+            min_length: old_min_length,
+            max_length: old_max_length,
+            format: old_format,
+        },
+        ParameterType::String {
+            pattern: new_pattern,
+            min_length: new_min_length,
+            max_length: new_max_length,
+            format: new_format,
+        },
+    ) = (old, new)
+    else {
+        return false;
+    };
+    let length_range = |minimum: &Option<u32>, maximum: &Option<u32>| {
+        (minimum.map(f64::from), maximum.map(f64::from))
+    };
+    is_range_narrowed(
+        length_range(old_min_length, old_max_length),
+        length_range(new_min_length, new_max_length),
+    ) || new_pattern.is_some() && new_pattern != old_pattern
+        || new_format.is_some() && new_format != old_format
+}
+
+/// 새 범위가 기존 범위에서 허용되던 값을 거부하는지 확인합니다.
+fn is_range_narrowed(old: (Option<f64>, Option<f64>), new: (Option<f64>, Option<f64>)) -> bool {
+    let minimum_narrowed = match (old.0, new.0) {
+        (_, None) => false,
+        (None, Some(_)) => true,
+        (Some(old), Some(new)) => new > old,
+    };
+    let maximum_narrowed = match (old.1, new.1) {
+        (_, None) => false,
+        (None, Some(_)) => true,
+        (Some(old), Some(new)) => new < old,
+    };
+    minimum_narrowed || maximum_narrowed
+}
+
+/// union과 intersection 멤버를 짝짓기 위한 키
+///
+/// `resourceRef` 멤버는 참조 대상으로, 인라인 멤버는 같은 종류의 멤버 중 몇 번째인지로 짝지어집니다.
+/// 제약 조건은 키에 포함하지 않으므로 짝지어진 멤버끼리 다시 비교해 보고합니다.
+fn member_keys(members: &[Parameter]) -> Vec<String> {
+    let mut counts = HashMap::new();
+    members
+        .iter()
+        .map(|member| {
+            let kind = match &member.r#type {
+                ParameterType::String { .. } => "string".to_string(),
+                ParameterType::Number { .. } => "number".to_string(),
+                ParameterType::Decimal { .. } => "decimal".to_string(),
+                r#type => type_name(r#type),
+            };
+            let count = counts.entry(kind.clone()).or_insert(0);
+            *count += 1;
+            format!("{kind}/{count}")
+        })
+        .collect()
+}
+
+struct Differ {
+    changes: Vec<Change>,
+    usage: HashMap<String, Direction>,
+}
+
+impl Differ {
+    fn report(
+        &mut self,
+        path: impl Into<String>,
+        kind: ChangeKind,
+        breaking: bool,
+        direction: Direction,
+        description: Option<&String>,
+    ) {
+        self.changes.push(Change {
+            path: path.into(),
+            kind,
+            breaking,
+            direction,
+            description: description.cloned(),
+        });
+    }
+
+    /// 메소드에서 사용되지 않는 리소스는 양방향으로 사용된다고 가정
+    fn resource_direction(&self, path: &str) -> Direction {
+        self.usage.get(path).copied().unwrap_or(Direction::BOTH)
+    }
+
+    fn diff_resources(
+        &mut self,
+        old_index: &IndexMap<String, Parameter>,
+        new_index: &IndexMap<String, Parameter>,
+    ) {
+        for (path, old) in old_index {
+            let resource_path = format!("#/resources/{path}");
+            let direction = self.resource_direction(path);
+            match new_index.get(path) {
+                Some(new) => self.diff_parameter(old, new, &resource_path, direction),
+                None => self.report(
+                    resource_path,
+                    ChangeKind::Removed(Element::Resource),
+                    true,
+                    direction,
+                    old.description.as_ref(),
+                ),
+            }
+        }
+        for (path, new) in new_index {
+            if !old_index.contains_key(path) {
+                self.report(
+                    format!("#/resources/{path}"),
+                    ChangeKind::Added(Element::Resource),
+                    false,
+                    self.resource_direction(path),
+                    new.description.as_ref(),
+                );
+            }
+        }
+    }
+
+    fn diff_methods(&mut self, old: &IndexMap<String, Method>, new: &IndexMap<String, Method>) {
+        for (name, old_method) in old {
+            let path = format!("#/methods/{name}");
+            let Some(new_method) = new.get(name) else {
+                self.report(
+                    path,
+                    ChangeKind::Removed(Element::Method),
+                    true,
+                    Direction::BOTH,
+                    old_method.description.as_ref(),
+                );
+                continue;
+            };
+            if !old_method.deprecated && new_method.deprecated {
+                self.report(
+                    &path,
+                    ChangeKind::Deprecated,
+                    false,
+                    Direction::BOTH,
+                    new_method.description.as_ref(),
+                );
+            }
+            self.diff_parameter(
+                &old_method.input,
+                &new_method.input,
+                &format!("{path}/input"),
+                Direction::INPUT,
+            );
+            let output_path = format!("{path}/output");
+            match (&old_method.output, &new_method.output) {
+                (Some(old_output), Some(new_output)) => {
+                    self.diff_parameter(old_output, new_output, &output_path, Direction::OUTPUT);
+                }
+                (Some(old_output), None) => self.report(
+                    output_path,
+                    ChangeKind::Removed(Element::Output),
+                    true,
+                    Direction::OUTPUT,
+                    old_output.description.as_ref(),
+                ),
+                (None, Some(new_output)) => self.report(
+                    output_path,
+                    ChangeKind::Added(Element::Output),
+                    false,
+                    Direction::OUTPUT,
+                    new_output.description.as_ref(),
+                ),
+                (None, None) => {}
+            }
+            let empty = IndexMap::new();
+            self.diff_callbacks(
+                old_method.callbacks.as_ref().unwrap_or(&empty),
+                new_method.callbacks.as_ref().unwrap_or(&empty),
+                &path,
+            );
+        }
+        for (name, new_method) in new {
+            if !old.contains_key(name) {
+                self.report(
+                    format!("#/methods/{name}"),
+                    ChangeKind::Added(Element::Method),
+                    false,
+                    Direction::BOTH,
+                    new_method.description.as_ref(),
+                );
+            }
+        }
+    }
+
+    fn diff_callbacks(
+        &mut self,
+        old: &IndexMap<String, Callback>,
+        new: &IndexMap<String, Callback>,
+        method_path: &str,
+    ) {
+        for (name, old_callback) in old {
+            let path = format!("{method_path}/callbacks/{name}");
+            let Some(new_callback) = new.get(name) else {
+                self.report(
+                    path,
+                    ChangeKind::Removed(Element::Callback),
+                    true,
+                    Direction::OUTPUT,
+                    old_callback.description.as_ref(),
+                );
+                continue;
+            };
+            if !old_callback.deprecated && new_callback.deprecated {
+                self.report(
+                    &path,
+                    ChangeKind::Deprecated,
+                    false,
+                    Direction::OUTPUT,
+                    new_callback.description.as_ref(),
+                );
+            }
+            // 콜백 파라미터가 바뀌면 클라이언트가 구현한 콜백의 시그니처가 달라집니다.
+            for (parameter_name, old_parameter) in &old_callback.input {
+                let parameter_path = format!("{path}/input/{parameter_name}");
+                match new_callback.input.get(parameter_name) {
+                    Some(new_parameter) => self.diff_parameter(
+                        old_parameter,
+                        new_parameter,
+                        &parameter_path,
+                        Direction::OUTPUT,
+                    ),
+                    None => self.report(
+                        parameter_path,
+                        ChangeKind::Removed(Element::CallbackParameter),
+                        true,
+                        Direction::OUTPUT,
+                        old_parameter.description.as_ref(),
+                    ),
+                }
+            }
+            for (parameter_name, new_parameter) in &new_callback.input {
+                if !old_callback.input.contains_key(parameter_name) {
+                    self.report(
+                        format!("{path}/input/{parameter_name}"),
+                        ChangeKind::Added(Element::CallbackParameter),
+                        true,
+                        Direction::OUTPUT,
+                        new_parameter.description.as_ref(),
+                    );
+                }
+            }
+        }
+        for (name, new_callback) in new {
+            if !old.contains_key(name) {
+                // Kotlin, Swift SDK에서는 콜백이 클라이언트가 구현해야 하는 interface 멤버나
+                // 메소드 파라미터로 생성되므로 콜백이 추가되면 기존 코드가 컴파일되지 않습니다.
+                self.report(
+                    format!("{method_path}/callbacks/{name}"),
+                    ChangeKind::Added(Element::Callback),
+                    true,
+                    Direction::OUTPUT,
+                    new_callback.description.as_ref(),
+                );
+            }
+        }
+    }
+
+    fn diff_parameter(
+        &mut self,
+        old: &Parameter,
+        new: &Parameter,
+        path: &str,
+        direction: Direction,
+    ) {
+        let description = new.description.as_ref();
+        match (old.optional, new.optional) {
+            // 클라이언트가 만드는 값에서 필수 항목이 늘어나면 기존 코드가 값을 채우지 않습니다.
+            (true, false) => self.report(
+                path,
+                ChangeKind::BecameRequired,
+                direction.input,
+                direction,
+                description,
+            ),
+            // 클라이언트가 받는 값이 없을 수 있게 되면 기존 코드가 값의 존재를 가정합니다.
+            (false, true) => self.report(
+                path,
+                ChangeKind::BecameOptional,
+                direction.output,
+                direction,
+                description,
+            ),
+            _ => {}
+        }
+        if !old.deprecated && new.deprecated {
+            self.report(path, ChangeKind::Deprecated, false, direction, description);
+        }
+        if old.default != new.default {
+            let to_string = |default: &Option<Box<serde_json::Value>>| {
+                default.as_ref().map(|default| default.to_string())
+            };
+            self.report(
+                path,
+                ChangeKind::DefaultChanged {
+                    from: to_string(&old.default),
+                    to: to_string(&new.default),
+                },
+                false,
+                direction,
+                description,
+            );
+        }
+
+        match (&old.r#type, &new.r#type) {
+            (ParameterType::Array { items: old, .. }, ParameterType::Array { items: new, .. }) => {
+                self.diff_parameter(old, new, &format!("{path}/items"), direction);
+            }
+            (ParameterType::Map { values: old }, ParameterType::Map { values: new }) => {
+                self.diff_parameter(old, new, &format!("{path}/values"), direction);
+            }
+            (
+                ParameterType::Object {
+                    properties: old, ..
+                },
+                ParameterType::Object {
+                    properties: new, ..
+                },
+            )
+            | (
+                ParameterType::Error {
+                    properties: old, ..
+                },
+                ParameterType::Error {
+                    properties: new, ..
+                },
+            ) => {
+                self.diff_properties(old, new, path, direction, |property| {
+                    direction.input && !property.optional
+                });
+            }
+            (
+                ParameterType::OneOf {
+                    properties: old, ..
+                },
+                ParameterType::OneOf {
+                    properties: new, ..
+                },
+            ) => {
+                // 새 선택지는 클라이언트가 받는 값에서만 처리되지 않은 경우가 생깁니다.
+                self.diff_properties(old, new, path, direction, |_| direction.output);
+            }
+            (
+                ParameterType::Enum { variants: old, .. },
+                ParameterType::Enum { variants: new, .. },
+            ) => {
+                for (value, old_variant) in old {
+                    let variant_path = format!("{path}/variants/{value}");
+                    match new.get(value) {
+                        Some(new_variant) => {
+                            if !old_variant.deprecated && new_variant.deprecated {
+                                self.report(
+                                    variant_path,
+                                    ChangeKind::Deprecated,
+                                    false,
+                                    direction,
+                                    new_variant.description.as_ref(),
+                                );
+                            }
+                        }
+                        None => self.report(
+                            variant_path,
+                            ChangeKind::Removed(Element::Variant),
+                            true,
+                            direction,
+                            old_variant.description.as_ref(),
+                        ),
+                    }
+                }
+                for (value, new_variant) in new {
+                    if !old.contains_key(value) {
+                        // 클라이언트가 받는 enum은 모든 variant를 처리하도록 작성되어 있을 수 있습니다.
+                        self.report(
+                            format!("{path}/variants/{value}"),
+                            ChangeKind::Added(Element::Variant),
+                            direction.output,
+                            direction,
+                            new_variant.description.as_ref(),
+                        );
+                    }
+                }
+            }
+            (ParameterType::Union { types: old, .. }, ParameterType::Union { types: new, .. }) => {
+                self.diff_members(old, new, path, direction, direction.output);
+            }
+            (
+                ParameterType::Intersection { types: old, .. },
+                ParameterType::Intersection { types: new, .. },
+            ) => {
+                self.diff_members(old, new, path, direction, direction.input);
+            }
+            (
+                ParameterType::Number {
+                    minimum: old_minimum,
+                    maximum: old_maximum,
+                },
+                ParameterType::Number {
+                    minimum: new_minimum,
+                    maximum: new_maximum,
+                },
+            )
+            | (
+                ParameterType::Decimal {
+                    minimum: old_minimum,
+                    maximum: old_maximum,
+                },
+                ParameterType::Decimal {
+                    minimum: new_minimum,
+                    maximum: new_maximum,
+                },
+            ) => {
+                let (old_range, new_range) =
+                    ((*old_minimum, *old_maximum), (*new_minimum, *new_maximum));
+                if old_range != new_range {
+                    // 범위가 좁아지면 기존 요청이, 넓어지면 기존 응답 처리가 호환되지 않음
+                    let breaking = is_range_narrowed(old_range, new_range) && direction.input
+                        || is_range_narrowed(new_range, old_range) && direction.output;
+                    self.report(
+                        path,
+                        ChangeKind::TypeChanged {
+                            from: type_name(&old.r#type),
+                            to: type_name(&new.r#type),
+                        },
+                        breaking,
+                        direction,
+                        description,
+                    );
+                }
+            }
+            (old_type @ ParameterType::String { .. }, new_type @ ParameterType::String { .. }) => {
+                if old_type != new_type {
+                    let breaking = is_string_narrowed(old_type, new_type) && direction.input
+                        || is_string_narrowed(new_type, old_type) && direction.output;
+                    self.report(
+                        path,
+                        ChangeKind::TypeChanged {
+                            from: type_name(old_type),
+                            to: type_name(new_type),
+                        },
+                        breaking,
+                        direction,
+                        description,
+                    );
+                }
+            }
+            (old_type, new_type) => {
+                let (from, to) = (type_name(old_type), type_name(new_type));
+                if from != to {
+                    self.report(
+                        path,
+                        ChangeKind::TypeChanged { from, to },
+                        true,
+                        direction,
+                        description,
+                    );
+                }
+            }
+        }
+    }
+
+    fn diff_properties(
+        &mut self,
+        old: &IndexMap<String, Parameter>,
+        new: &IndexMap<String, Parameter>,
+        path: &str,
+        direction: Direction,
+        is_breaking_addition: impl Fn(&Parameter) -> bool,
+    ) {
+        for (name, old_property) in old {
+            let property_path = format!("{path}/properties/{name}");
+            match new.get(name) {
+                Some(new_property) => {
+                    self.diff_parameter(old_property, new_property, &property_path, direction)
+                }
+                None => self.report(
+                    property_path,
+                    ChangeKind::Removed(Element::Property),
+                    true,
+                    direction,
+                    old_property.description.as_ref(),
+                ),
+            }
+        }
+        for (name, new_property) in new {
+            if !old.contains_key(name) {
+                self.report(
+                    format!("{path}/properties/{name}"),
+                    ChangeKind::Added(Element::Property),
+                    is_breaking_addition(new_property),
+                    direction,
+                    new_property.description.as_ref(),
+                );
+            }
+        }
+    }
+
+    /// 멤버가 빠지는 변경은 항상 호환되지 않으며, 멤버 추가는 `is_breaking_addition`에 따릅니다.
+    /// 양쪽에 모두 있는 멤버는 내부의 변경 사항까지 비교합니다.
+    fn diff_members(
+        &mut self,
+        old: &[Parameter],
+        new: &[Parameter],
+        path: &str,
+        direction: Direction,
+        is_breaking_addition: bool,
+    ) {
+        let old_keys = member_keys(old);
+        let new_keys = member_keys(new);
+        for (i, key) in old_keys.iter().enumerate() {
+            match new_keys.iter().position(|new_key| new_key == key) {
+                Some(j) => {
+                    self.diff_parameter(&old[i], &new[j], &format!("{path}/types/{j}"), direction)
+                }
+                None => self.report(
+                    format!("{path}/types/{i}"),
+                    ChangeKind::Removed(Element::Member),
+                    true,
+                    direction,
+                    old[i].description.as_ref(),
+                ),
+            }
+        }
+        for (i, key) in new_keys.iter().enumerate() {
+            if !old_keys.contains(key) {
+                self.report(
+                    format!("{path}/types/{i}"),
+                    ChangeKind::Added(Element::Member),
+                    is_breaking_addition,
+                    direction,
+                    new[i].description.as_ref(),
+                );
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    fn schema(yaml: &str) -> Schema {
+        serde_yaml_ng::from_str(yaml).unwrap()
+    }
+
+    fn changes(old: &str, new: &str) -> Vec<String> {
+        schema(old)
+            .diff(&schema(new))
+            .changes
+            .iter()
+            .map(|change| {
+                let label = if change.breaking {
+                    "breaking"
+                } else {
+                    "non-breaking"
+                };
+                format!("{label}: {change}")
+            })
+            .collect()
+    }
+
+    const OLD: &str = r##"
+flags: {}
+resources:
+  entity:
+    PayMethod:
+      type: enum
+      variants:
+        CARD: {}
+        VIRTUAL_ACCOUNT: {}
+  request:
+    PaymentRequest:
+      type: object
+      properties:
+        storeId:
+          type: string
+        customerId:
+          type: string
+          optional: true
+        payMethod:
+          type: resourceRef
+          $ref: '#/resources/entity/PayMethod'
+  response:
+    PaymentResponse:
+      type: object
+      properties:
+        txId:
+          type: string
+        payMethod:
+          type: resourceRef
+          $ref: '#/resources/entity/PayMethod'
+methods:
+  requestPayment:
+    input:
+      type: resourceRef
+      $ref: '#/resources/request/PaymentRequest'
+    output:
+      type: resourceRef
+      $ref: '#/resources/response/PaymentResponse'
+"##;
+
+    #[test]
+    fn identical_schemas_have_no_changes() {
+        let diff = schema(OLD).diff(&schema(OLD));
+        assert!(diff.changes.is_empty());
+        assert_eq!(diff.suggested_bump(), Bump::Patch);
+    }
+
+    #[test]
+    fn classifies_changes_by_direction() {
+        let new = OLD
+            .replace(
+                "        customerId:\n          type: string\n          optional: true\n",
+                "        customerId:\n          type: string\n        \
+                 channelKey:\n          type: string\n          optional: true\n",
+            )
+            .replace(
+                "        txId:\n          type: string\n",
+                "        txId:\n          type: string\n          optional: true\n",
+            )
+            .replace("        VIRTUAL_ACCOUNT: {}\n", "        TRANSFER: {}\n");
+        assert_eq!(
+            changes(OLD, &new),
+            vec![
+                "breaking: #/resources/entity/PayMethod/variants/VIRTUAL_ACCOUNT: enum variant removed",
+                "breaking: #/resources/entity/PayMethod/variants/TRANSFER: enum variant added",
+                "breaking: #/resources/request/PaymentRequest/properties/customerId: became required",
+                "non-breaking: #/resources/request/PaymentRequest/properties/channelKey: property added",
+                "breaking: #/resources/response/PaymentResponse/properties/txId: became optional",
+            ]
+        );
+    }
+
+    #[test]
+    fn narrowed_union_is_breaking() {
+        let old = r##"
+flags: {}
+resources:
+  entity:
+    Card:
+      type: object
+      properties: {}
+    Transfer:
+      type: object
+      properties: {}
+    Method:
+      type: union
+      types:
+        - type: resourceRef
+          $ref: '#/resources/entity/Card'
+        - type: resourceRef
+          $ref: '#/resources/entity/Transfer'
+methods: {}
+"##;
+        let new = old.replace(
+            "        - type: resourceRef\n          $ref: '#/resources/entity/Transfer'\n",
+            "",
+        );
+        let diff = schema(old).diff(&schema(&new));
+        assert_eq!(
+            changes(old, &new),
+            vec!["breaking: #/resources/entity/Method/types/1: member removed"]
+        );
+        assert_eq!(diff.suggested_bump(), Bump::Major);
+    }
+
+    #[test]
+    fn diffs_inline_members() {
+        let old = r##"
+flags: {}
+resources:
+  entity:
+    Base:
+      type: object
+      properties: {}
+  request:
+    Request:
+      type: intersection
+      types:
+        - type: resourceRef
+          $ref: '#/resources/entity/Base'
+        - type: object
+          properties:
+            amount:
+              type: integer
+methods:
+  request:
+    input:
+      type: resourceRef
+      $ref: '#/resources/request/Request'
+"##;
+        let new = old.replace(
+            "            amount:\n              type: integer\n",
+            "            amount:\n              type: integer\n            \
+             currency:\n              type: string\n",
+        );
+        let diff = schema(old).diff(&schema(&new));
+        assert_eq!(
+            changes(old, &new),
+            vec![
+                "breaking: #/resources/request/Request/types/1/properties/currency: property added"
+            ]
+        );
+        assert_eq!(diff.suggested_bump(), Bump::Major);
+    }
+
+    #[test]
+    fn added_callback_is_breaking() {
+        let old = r##"
+flags: {}
+resources: {}
+methods:
+  loadPaymentUI:
+    input:
+      type: object
+      properties: {}
+    callbacks:
+      onPaymentSuccess:
+        input: {}
+"##;
+        let new = format!("{old}      onPaymentFail:\n        input: {{}}\n");
+        assert_eq!(
+            changes(old, &new),
+            vec!["breaking: #/methods/loadPaymentUI/callbacks/onPaymentFail: callback added"]
+        );
+    }
+
+    #[test]
+    fn range_changes_are_breaking_by_direction() {
+        let old = r##"
+flags: {}
+resources:
+  request:
+    Request:
+      type: object
+      properties:
+        amount:
+          type: decimal
+          minimum: 0
+        fee:
+          type: decimal
+          minimum: 10
+  response:
+    Response:
+      type: object
+      properties:
+        rate:
+          type: number
+          maximum: 1
+methods:
+  request:
+    input:
+      type: resourceRef
+      $ref: '#/resources/request/Request'
+    output:
+      type: resourceRef
+      $ref: '#/resources/response/Response'
+"##;
+        let new = old
+            .replace("minimum: 0\n", "minimum: 100\n")
+            .replace("minimum: 10\n", "minimum: 0\n")
+            .replace("maximum: 1\n", "maximum: 2\n");
+        assert_eq!(
+            changes(old, &new),
+            vec![
+                "breaking: #/resources/request/Request/properties/amount: type changed from decimal (minimum 0) to decimal (minimum 100)",
+                "non-breaking: #/resources/request/Request/properties/fee: type changed from decimal (minimum 10) to decimal (minimum 0)",
+                "breaking: #/resources/response/Response/properties/rate: type changed from number (maximum 1) to number (maximum 2)",
+            ]
+        );
+    }
+
+    #[test]
+    fn string_constraint_changes_are_breaking_by_direction() {
+        let old = r##"
+flags: {}
+resources:
+  request:
+    Request:
+      type: object
+      properties:
+        email:
+          type: string
+        memo:
+          type: string
+          maxLength: 100
+  response:
+    Response:
+      type: object
+      properties:
+        code:
+          type: string
+          pattern: '^[A-Z]+$'
+methods:
+  request:
+    input:
+      type: resourceRef
+      $ref: '#/resources/request/Request'
+    output:
+      type: resourceRef
+      $ref: '#/resources/response/Response'
+"##;
+        let new = old
+            .replace(
+                "email:\n          type: string\n",
+                "email:\n          type: string\n          format: email\n",
+            )
+            .replace("maxLength: 100\n", "maxLength: 200\n")
+            .replace("          pattern: '^[A-Z]+$'\n", "");
+        assert_eq!(
+            changes(old, &new),
+            vec![
+                "breaking: #/resources/request/Request/properties/email: type changed from string to string (format email)",
+                "non-breaking: #/resources/request/Request/properties/memo: type changed from string (maxLength 100) to string (maxLength 200)",
+                "breaking: #/resources/response/Response/properties/code: type changed from string (pattern `^[A-Z]+$`) to string",
+            ]
+        );
+    }
+
+    #[test]
+    fn default_changes_are_reported() {
+        let old = r##"
+flags: {}
+resources:
+  request:
+    Request:
+      type: object
+      properties:
+        locale:
+          type: string
+          optional: true
+          default: KO_KR
+        isTestChannel:
+          type: boolean
+          optional: true
+methods: {}
+"##;
+        let new = old.replace("default: KO_KR", "default: EN_US").replace(
+            "type: boolean\n          optional: true\n",
+            "type: boolean\n          optional: true\n          default: false\n",
+        );
+        assert_eq!(
+            changes(old, &new),
+            vec![
+                "non-breaking: #/resources/request/Request/properties/locale: default changed from `\"KO_KR\"` to `\"EN_US\"`",
+                "non-breaking: #/resources/request/Request/properties/isTestChannel: default changed from none to `false`",
+            ]
+        );
+    }
+}
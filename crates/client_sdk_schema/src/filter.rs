@@ -0,0 +1,361 @@
+use indexmap::IndexMap;
+
+use crate::{
+    EnumVariant, FlagOption, Parameter, ParameterType, Resource, Schema, ValidationError,
+    ValidationErrorKind,
+    version::{Version, is_available},
+};
+
+/// 스키마에서 남길 리소스, 프로퍼티, enum variant 및 union/intersection 멤버를 고르는 기준
+trait Retain {
+    fn retain_parameter(&self, parameter: &Parameter) -> bool;
+    fn retain_variant(&self, variant: &EnumVariant) -> bool;
+}
+
+/// 선택된 플래그 중 하나라도 `visible`로 표시되어 있으면 노출합니다.
+/// `flagOptions`가 없는 항목은 항상 노출됩니다.
+struct FlagRetain<'a>(&'a [String]);
+
+impl FlagRetain<'_> {
+    fn is_visible(&self, flag_options: Option<&IndexMap<String, FlagOption>>) -> bool {
+        match flag_options {
+            Some(flag_options) => self
+                .0
+                .iter()
+                .any(|flag| flag_options.get(flag).is_some_and(FlagOption::visible)),
+            None => true,
+        }
+    }
+}
+
+impl Retain for FlagRetain<'_> {
+    fn retain_parameter(&self, parameter: &Parameter) -> bool {
+        self.is_visible(parameter.flag_options.as_ref())
+    }
+
+    fn retain_variant(&self, variant: &EnumVariant) -> bool {
+        self.is_visible(variant.flag_options.as_ref())
+    }
+}
+
+/// 주어진 버전에 존재하는 항목만 남깁니다.
+struct VersionRetain<'a>(&'a Version);
+
+impl Retain for VersionRetain<'_> {
+    fn retain_parameter(&self, parameter: &Parameter) -> bool {
+        is_available(
+            parameter.since.as_ref(),
+            parameter.removed_in.as_ref(),
+            self.0,
+        )
+    }
+
+    fn retain_variant(&self, variant: &EnumVariant) -> bool {
+        is_available(variant.since.as_ref(), variant.removed_in.as_ref(), self.0)
+    }
+}
+
+impl Schema {
+    /// 선택된 플래그(PG)에서 보이지 않는 리소스, 프로퍼티, enum variant, oneOf 멤버 및 union/intersection 멤버를 제거합니다.
+    pub fn retain_flags(&mut self, flags: &[String]) -> Result<(), String> {
+        if let Some(flag) = flags.iter().find(|flag| !self.flags.contains_key(*flag)) {
+            return Err(format!("unknown flag `{flag}`"));
+        }
+        self.retain(&FlagRetain(flags))
+    }
+
+    /// 주어진 버전에 아직 추가되지 않았거나 이미 제거된 항목을 제거합니다.
+    pub fn retain_version(&mut self, version: &Version) -> Result<(), String> {
+        self.retain(&VersionRetain(version))
+    }
+
+    /// 제거된 리소스를 가리키는 `$ref`가 남으면 에러를 반환합니다.
+    fn retain(&mut self, retain: &impl Retain) -> Result<(), String> {
+        let dangling_refs = self.dangling_refs();
+        retain_resource(&mut self.resources, retain);
+        for method in self.methods.values_mut() {
+            retain_parameter(&mut method.input, retain);
+            if let Some(output) = &mut method.output {
+                retain_parameter(output, retain);
+            }
+            for callback in method.callbacks.iter_mut().flat_map(IndexMap::values_mut) {
+                retain_properties(&mut callback.input, retain);
+            }
+        }
+        match self
+            .dangling_refs()
+            .into_iter()
+            .find(|error| !dangling_refs.contains(error))
+        {
+            Some(error) => Err(format!("{error} after filtering")),
+            None => Ok(()),
+        }
+    }
+
+    fn dangling_refs(&self) -> Vec<ValidationError> {
+        self.validate()
+            .into_iter()
+            .filter(|error| matches!(error.kind, ValidationErrorKind::DanglingRef { .. }))
+            .collect()
+    }
+}
+
+fn retain_resource(resource: &mut Resource, retain: &impl Retain) {
+    match resource {
+        Resource::SubResources(sub_resources) => {
+            sub_resources.retain(|_, sub_resource| match sub_resource {
+                Resource::Parameter(parameter) => retain.retain_parameter(parameter),
+                Resource::SubResources(_) => true,
+            });
+            for sub_resource in sub_resources.values_mut() {
+                retain_resource(sub_resource, retain);
+            }
+        }
+        Resource::Parameter(parameter) => retain_parameter(parameter, retain),
+    }
+}
+
+fn retain_properties(properties: &mut IndexMap<String, Parameter>, retain: &impl Retain) {
+    properties.retain(|_, property| retain.retain_parameter(property));
+    for property in properties.values_mut() {
+        retain_parameter(property, retain);
+    }
+}
+
+fn retain_parameter(parameter: &mut Parameter, retain: &impl Retain) {
+    match &mut parameter.r#type {
+        ParameterType::Array { items, .. } | ParameterType::Map { values: items } => {
+            retain_parameter(items, retain)
+        }
+        ParameterType::Object { properties, .. }
+        | ParameterType::OneOf { properties, .. }
+        | ParameterType::Error { properties, .. } => retain_properties(properties, retain),
+        ParameterType::Enum { variants, .. } => {
+            variants.retain(|_, variant| retain.retain_variant(variant));
+        }
+        ParameterType::Union { types, .. } | ParameterType::Intersection { types, .. } => {
+            types.retain(|member| retain.retain_parameter(member));
+            for member in types.iter_mut() {
+                retain_parameter(member, retain);
+            }
+        }
+        _ => {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Flag;
+    use pretty_assertions::assert_eq;
+
+    fn flag_options(entries: &[(&str, bool)]) -> Option<IndexMap<String, FlagOption>> {
+        Some(
+            entries
+                .iter()
+                .map(|(flag, visible)| (flag.to_string(), FlagOption::new(*visible)))
+                .collect(),
+        )
+    }
+
+    fn schema() -> Schema {
+        let properties = IndexMap::from([
+            ("storeId".to_string(), Parameter::default()),
+            (
+                "bypass".to_string(),
+                Parameter {
+                    flag_options: flag_options(&[("kcp", true), ("tosspayments", false)]),
+                    since: Some(Version::new(2, 0, 0)),
+                    ..Default::default()
+                },
+            ),
+        ]);
+        let variants = IndexMap::from([
+            (
+                "CARD".to_string(),
+                EnumVariant {
+                    description: None,
+                    alias: None,
+                    flag_options: None,
+                    deprecated: false,
+                    replacement: None,
+                    since: None,
+                    removed_in: None,
+                },
+            ),
+            (
+                "PAYPAL".to_string(),
+                EnumVariant {
+                    description: None,
+                    alias: None,
+                    flag_options: flag_options(&[("paypal", true)]),
+                    deprecated: false,
+                    replacement: None,
+                    since: None,
+                    removed_in: Some(Version::new(3, 0, 0)),
+                },
+            ),
+        ]);
+        Schema {
+            flags: ["kcp", "tosspayments", "paypal"]
+                .into_iter()
+                .map(|flag| {
+                    (
+                        flag.to_string(),
+                        Flag {
+                            description: flag.to_string(),
+                        },
+                    )
+                })
+                .collect(),
+            resources: Resource::SubResources(IndexMap::from([
+                (
+                    "Request".to_string(),
+                    Resource::Parameter(Parameter {
+                        r#type: ParameterType::Object {
+                            properties,
+                            hide_if_empty: false,
+                        },
+                        ..Default::default()
+                    }),
+                ),
+                (
+                    "PayMethod".to_string(),
+                    Resource::Parameter(Parameter {
+                        r#type: ParameterType::Enum {
+                            variants,
+                            value_prefix: None,
+                        },
+                        ..Default::default()
+                    }),
+                ),
+            ])),
+            methods: IndexMap::new(),
+            version: None,
+        }
+    }
+
+    fn retained_names(schema: &Schema) -> (Vec<String>, Vec<String>) {
+        let index = schema.build_resource_index();
+        let ParameterType::Object { properties, .. } = &index["Request"].r#type else {
+            unreachable!()
+        };
+        let ParameterType::Enum { variants, .. } = &index["PayMethod"].r#type else {
+            unreachable!()
+        };
+        (
+            properties.keys().cloned().collect(),
+            variants.keys().cloned().collect(),
+        )
+    }
+
+    #[test]
+    fn test_retain_flags() {
+        let mut kcp = schema();
+        kcp.retain_flags(&["kcp".to_string()]).unwrap();
+        assert_eq!(
+            retained_names(&kcp),
+            (
+                vec!["storeId".to_string(), "bypass".to_string()],
+                vec!["CARD".to_string()]
+            )
+        );
+
+        let mut tosspayments = schema();
+        tosspayments
+            .retain_flags(&["tosspayments".to_string(), "paypal".to_string()])
+            .unwrap();
+        assert_eq!(
+            retained_names(&tosspayments),
+            (
+                vec!["storeId".to_string()],
+                vec!["CARD".to_string(), "PAYPAL".to_string()]
+            )
+        );
+    }
+
+    #[test]
+    fn test_retain_unknown_flag() {
+        assert_eq!(
+            schema().retain_flags(&["unknown".to_string()]),
+            Err("unknown flag `unknown`".to_string())
+        );
+    }
+
+    #[test]
+    fn test_retain_version() {
+        let mut v1 = schema();
+        v1.retain_version(&Version::new(1, 0, 0)).unwrap();
+        assert_eq!(
+            retained_names(&v1),
+            (
+                vec!["storeId".to_string()],
+                vec!["CARD".to_string(), "PAYPAL".to_string()]
+            )
+        );
+
+        let mut v3 = schema();
+        v3.retain_version(&Version::new(3, 0, 0)).unwrap();
+        assert_eq!(
+            retained_names(&v3),
+            (
+                vec!["storeId".to_string(), "bypass".to_string()],
+                vec!["CARD".to_string()]
+            )
+        );
+    }
+
+    #[test]
+    fn test_retain_version_resources() {
+        let schema: Schema = serde_yaml_ng::from_str(
+            r##"
+flags: {}
+resources:
+  entity:
+    Refund:
+      type: object
+      since: 2.0.0
+      properties: {}
+    Payment:
+      type: object
+      properties:
+        refund:
+          type: resourceRef
+          $ref: '#/resources/entity/Refund'
+          since: 2.0.0
+methods: {}
+"##,
+        )
+        .unwrap();
+        let mut v1 = schema.clone();
+        v1.retain_version(&Version::new(1, 0, 0)).unwrap();
+        let index = v1.build_resource_index();
+        assert_eq!(index.keys().collect::<Vec<_>>(), vec!["entity/Payment"]);
+        let ParameterType::Object { properties, .. } = &index["entity/Payment"].r#type else {
+            unreachable!()
+        };
+        assert!(properties.is_empty());
+
+        let mut dangling = schema.clone();
+        let Resource::SubResources(resources) = &mut dangling.resources else {
+            unreachable!()
+        };
+        let Resource::SubResources(entity) = &mut resources["entity"] else {
+            unreachable!()
+        };
+        let Resource::Parameter(payment) = &mut entity["Payment"] else {
+            unreachable!()
+        };
+        let ParameterType::Object { properties, .. } = &mut payment.r#type else {
+            unreachable!()
+        };
+        properties["refund"].since = None;
+        assert_eq!(
+            dangling.retain_version(&Version::new(1, 0, 0)),
+            Err(
+                "#/resources/entity/Payment/properties/refund: `$ref` points to missing resource `entity/Refund` after filtering"
+                    .to_string()
+            )
+        );
+    }
+}
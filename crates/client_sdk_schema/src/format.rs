@@ -0,0 +1,47 @@
+use std::path::Path;
+
+use serde_yaml_ng::Value;
+
+/// 스키마 파일 형식
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Format {
+    Yaml,
+    Json,
+    Toml,
+}
+
+impl Format {
+    /// 확장자로 형식을 판단합니다. 표준 입력처럼 확장자가 없으면 JSON도 읽을 수 있는 YAML로 취급합니다.
+    pub(crate) fn from_path(path: &Path) -> Format {
+        match path.extension().and_then(|extension| extension.to_str()) {
+            Some("json") => Format::Json,
+            Some("toml") => Format::Toml,
+            _ => Format::Yaml,
+        }
+    }
+
+    pub(crate) fn parse(self, content: &str) -> Result<Value, String> {
+        match self {
+            Format::Yaml => serde_yaml_ng::from_str(content).map_err(|e| e.to_string()),
+            Format::Json => serde_json::from_str(content).map_err(|e| e.to_string()),
+            Format::Toml => toml::from_str(content).map_err(|e| e.to_string()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn parses_every_format_into_the_same_value() {
+        let yaml = Format::Yaml.parse("flags:\n  kcp:\n    description: KCP\n");
+        let json = Format::Json.parse(r#"{"flags": {"kcp": {"description": "KCP"}}}"#);
+        let toml = Format::Toml.parse("[flags.kcp]\ndescription = \"KCP\"\n");
+        assert_eq!(json, yaml);
+        assert_eq!(toml, yaml);
+        assert_eq!(Format::from_path(Path::new("schema.toml")), Format::Toml);
+        assert_eq!(Format::from_path(Path::new("-")), Format::Yaml);
+    }
+}
@@ -0,0 +1,331 @@
+use std::collections::HashSet;
+
+use convert_case::{Case, Casing};
+
+use crate::{Parameter, ParameterType, Resource, ResourceRef, Schema};
+
+impl Schema {
+    /// 리소스 안에 인라인으로 정의된 객체 타입을 같은 디렉토리의 리소스로 분리하고
+    /// `resourceRef`로 대체합니다.
+    ///
+    /// 모바일 SDK는 이름이 있는 타입만 생성할 수 있으므로, 분리된 리소스의 이름은
+    /// TypeScript 생성기와 같이 `{상위 타입}{프로퍼티}`, `{상위 타입}Item`,
+    /// `{상위 타입}Value`, `{상위 타입}Union{i}`, `{상위 타입}Intersection{i}` 형식으로 지어집니다.
+    /// Swift는 모든 타입을 한 모듈에 생성하므로 다른 디렉토리의 리소스와도 이름이 겹치지 않게 합니다.
+    pub fn hoist_inline_types(&mut self) {
+        let mut names = HashSet::new();
+        collect_names(&self.resources, &mut names);
+        hoist_resource(&mut self.resources, "", &mut names);
+    }
+}
+
+/// 리소스와 디렉토리의 이름, 리소스에 지정된 타입 이름을 모읍니다.
+fn collect_names(resource: &Resource, names: &mut HashSet<String>) {
+    match resource {
+        Resource::SubResources(sub_resources) => {
+            for (name, sub_resource) in sub_resources {
+                names.insert(name.clone());
+                collect_names(sub_resource, names);
+            }
+        }
+        Resource::Parameter(parameter) => names.extend(parameter.name.clone()),
+    }
+}
+
+fn hoist_resource(resource: &mut Resource, path: &str, names: &mut HashSet<String>) {
+    let Resource::SubResources(sub_resources) = resource else {
+        return;
+    };
+    let mut hoister = Hoister {
+        path,
+        names,
+        hoisted: vec![],
+    };
+    for (name, sub_resource) in sub_resources.iter_mut() {
+        match sub_resource {
+            Resource::SubResources(_) => {
+                let sub_path = if path.is_empty() {
+                    name.clone()
+                } else {
+                    format!("{path}/{name}")
+                };
+                hoist_resource(sub_resource, &sub_path, hoister.names);
+            }
+            Resource::Parameter(parameter) => {
+                let parent_name = parameter.name.clone().unwrap_or_else(|| name.clone());
+                hoister.hoist_type(&mut parameter.r#type, &parent_name);
+            }
+        }
+    }
+    for (name, parameter) in hoister.hoisted {
+        sub_resources.insert(name, Resource::Parameter(parameter));
+    }
+}
+
+/// 한 디렉토리에서 분리된 리소스를 모읍니다.
+struct Hoister<'a> {
+    path: &'a str,
+    /// 스키마 전체에서 이미 사용 중인 이름
+    names: &'a mut HashSet<String>,
+    hoisted: Vec<(String, Parameter)>,
+}
+
+impl Hoister<'_> {
+    fn hoist_type(&mut self, r#type: &mut ParameterType, parent_name: &str) {
+        match r#type {
+            ParameterType::Object { properties, .. }
+            | ParameterType::OneOf { properties, .. }
+            | ParameterType::Error { properties, .. } => {
+                for (name, property) in properties.iter_mut() {
+                    let name = format!("{parent_name}{}", name.to_case(Case::Pascal));
+                    self.hoist_parameter(property, &name);
+                }
+            }
+            ParameterType::Array { items, .. } => {
+                self.hoist_parameter(items, &format!("{parent_name}Item"));
+            }
+            ParameterType::Map { values } => {
+                self.hoist_parameter(values, &format!("{parent_name}Value"));
+            }
+            ParameterType::Union { types, .. } => {
+                for (i, member) in types.iter_mut().enumerate() {
+                    self.hoist_parameter(member, &format!("{parent_name}Union{i}"));
+                }
+            }
+            ParameterType::Intersection { types, .. } => {
+                for (i, member) in types.iter_mut().enumerate() {
+                    self.hoist_parameter(member, &format!("{parent_name}Intersection{i}"));
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn hoist_parameter(&mut self, parameter: &mut Parameter, name: &str) {
+        self.hoist_type(&mut parameter.r#type, name);
+        if !matches!(
+            parameter.r#type,
+            ParameterType::Object { .. }
+                | ParameterType::EmptyObject
+                | ParameterType::OneOf { .. }
+                | ParameterType::Union { .. }
+                | ParameterType::Intersection { .. }
+        ) {
+            return;
+        }
+        // 같은 이름의 리소스가 이미 있으면 번호를 붙임
+        let mut unique_name = name.to_string();
+        let mut suffix = 2;
+        while self.names.contains(&unique_name) {
+            unique_name = format!("{name}{suffix}");
+            suffix += 1;
+        }
+        let name = unique_name;
+        let resource_ref = if self.path.is_empty() {
+            format!("#/resources/{name}")
+        } else {
+            format!("#/resources/{}/{name}", self.path)
+        };
+        let r#type = std::mem::replace(
+            &mut parameter.r#type,
+            ParameterType::ResourceRef(ResourceRef::new(&resource_ref)),
+        );
+        let hoisted = Parameter {
+            description: parameter.description.clone(),
+            r#type,
+            deprecated: parameter.deprecated,
+            ..Default::default()
+        };
+        self.names.insert(name.clone());
+        self.hoisted.push((name, hoisted));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use indexmap::IndexMap;
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    fn parameter(r#type: ParameterType) -> Parameter {
+        Parameter {
+            r#type,
+            ..Default::default()
+        }
+    }
+
+    fn object(properties: &[(&str, Parameter)]) -> ParameterType {
+        ParameterType::Object {
+            properties: properties
+                .iter()
+                .map(|(name, parameter)| (name.to_string(), parameter.clone()))
+                .collect(),
+            hide_if_empty: false,
+        }
+    }
+
+    fn resource_ref(path: &str) -> Parameter {
+        parameter(ParameterType::ResourceRef(ResourceRef::new(path)))
+    }
+
+    #[test]
+    fn hoists_nested_objects() {
+        let item = object(&[
+            ("name", parameter(ParameterType::string())),
+            (
+                "tax_info",
+                Parameter {
+                    description: Some("세금 정보".to_string()),
+                    optional: true,
+                    ..parameter(object(&[("rate", parameter(ParameterType::Integer))]))
+                },
+            ),
+        ]);
+        let order = object(&[(
+            "items",
+            parameter(ParameterType::Array {
+                items: Box::new(parameter(item)),
+                hide_if_empty: false,
+            }),
+        )]);
+        let mut schema = Schema {
+            version: None,
+            flags: IndexMap::new(),
+            resources: Resource::SubResources(IndexMap::from([(
+                "entity".to_string(),
+                Resource::SubResources(IndexMap::from([
+                    ("Order".to_string(), Resource::Parameter(parameter(order))),
+                    (
+                        "OrderItemsItem".to_string(),
+                        Resource::Parameter(parameter(ParameterType::EmptyObject)),
+                    ),
+                ])),
+            )])),
+            methods: IndexMap::new(),
+        };
+        schema.hoist_inline_types();
+
+        let index = schema.build_resource_index();
+        assert_eq!(
+            index.keys().collect::<Vec<_>>(),
+            [
+                "entity/Order",
+                "entity/OrderItemsItem",
+                "entity/OrderItemsItemTaxInfo",
+                "entity/OrderItemsItem2",
+            ]
+        );
+        assert_eq!(
+            index["entity/Order"].r#type,
+            object(&[(
+                "items",
+                parameter(ParameterType::Array {
+                    items: Box::new(resource_ref("#/resources/entity/OrderItemsItem2")),
+                    hide_if_empty: false,
+                }),
+            )])
+        );
+        assert_eq!(
+            index["entity/OrderItemsItem2"].r#type,
+            object(&[
+                ("name", parameter(ParameterType::string())),
+                (
+                    "tax_info",
+                    Parameter {
+                        description: Some("세금 정보".to_string()),
+                        optional: true,
+                        ..resource_ref("#/resources/entity/OrderItemsItemTaxInfo")
+                    },
+                ),
+            ])
+        );
+        assert_eq!(
+            index["entity/OrderItemsItemTaxInfo"],
+            Parameter {
+                description: Some("세금 정보".to_string()),
+                ..parameter(object(&[("rate", parameter(ParameterType::Integer))]))
+            }
+        );
+    }
+
+    #[test]
+    fn hoists_object_union_members() {
+        let literal = parameter(ParameterType::StringLiteral {
+            value: "NONE".to_string(),
+        });
+        let card = object(&[("number", parameter(ParameterType::string()))]);
+        let mut schema = Schema {
+            version: None,
+            flags: IndexMap::new(),
+            resources: Resource::SubResources(IndexMap::from([(
+                "entity".to_string(),
+                Resource::SubResources(IndexMap::from([(
+                    "Method".to_string(),
+                    Resource::Parameter(parameter(ParameterType::Union {
+                        types: vec![literal.clone(), parameter(card.clone())],
+                        hide_if_empty: false,
+                    })),
+                )])),
+            )])),
+            methods: IndexMap::new(),
+        };
+        schema.hoist_inline_types();
+
+        let index = schema.build_resource_index();
+        assert_eq!(
+            index["entity/Method"].r#type,
+            ParameterType::Union {
+                types: vec![literal, resource_ref("#/resources/entity/MethodUnion1")],
+                hide_if_empty: false,
+            }
+        );
+        assert_eq!(index["entity/MethodUnion1"].r#type, card);
+    }
+
+    #[test]
+    fn avoids_names_from_other_directories() {
+        let order = object(&[(
+            "items",
+            parameter(ParameterType::Array {
+                items: Box::new(parameter(object(&[(
+                    "name",
+                    parameter(ParameterType::string()),
+                )]))),
+                hide_if_empty: false,
+            }),
+        )]);
+        let mut schema = Schema {
+            version: None,
+            flags: IndexMap::new(),
+            resources: Resource::SubResources(IndexMap::from([
+                (
+                    "entity".to_string(),
+                    Resource::SubResources(IndexMap::from([(
+                        "Item".to_string(),
+                        Resource::Parameter(Parameter {
+                            name: Some("OrderItemsItem".to_string()),
+                            ..parameter(ParameterType::EmptyObject)
+                        }),
+                    )])),
+                ),
+                (
+                    "request".to_string(),
+                    Resource::SubResources(IndexMap::from([(
+                        "Order".to_string(),
+                        Resource::Parameter(parameter(order)),
+                    )])),
+                ),
+            ])),
+            methods: IndexMap::new(),
+        };
+        schema.hoist_inline_types();
+
+        let index = schema.build_resource_index();
+        assert_eq!(
+            index.keys().collect::<Vec<_>>(),
+            ["entity/Item", "request/Order", "request/OrderItemsItem2"]
+        );
+        assert_eq!(schema.validate(), vec![]);
+    }
+}
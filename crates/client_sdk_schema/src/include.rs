@@ -0,0 +1,356 @@
+use std::{
+    collections::HashMap,
+    fmt, fs,
+    path::{Component, Path, PathBuf},
+};
+
+use serde_yaml_ng::{Mapping, Value};
+
+use crate::{Resource, Schema, format::Format};
+
+/// 다른 파일에서 가져온 스키마 영역
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Include {
+    /// 병합된 스키마에서의 위치 (예: `#/resources/entity/Bank`)
+    pub path: String,
+    /// 가져온 파일과 파일 내 위치 (예: `schema/entity/bank.yml#/Bank`)
+    pub source: String,
+}
+
+/// `$ref` include가 모두 해석된 스키마
+#[derive(Debug)]
+pub struct SchemaFile {
+    pub schema: Schema,
+    pub includes: Vec<Include>,
+}
+
+impl SchemaFile {
+    /// 병합된 스키마의 위치가 정의된 파일을 찾습니다. 최상위 파일에 정의되어 있으면 `None`을 반환합니다.
+    pub fn source_of(&self, path: &str) -> Option<&Include> {
+        self.includes
+            .iter()
+            .filter(|include| {
+                path.strip_prefix(&include.path)
+                    .is_some_and(|rest| rest.is_empty() || rest.starts_with('/'))
+            })
+            .max_by_key(|include| include.path.len())
+    }
+}
+
+#[derive(Debug)]
+pub enum LoadError {
+    Read {
+        path: PathBuf,
+        error: std::io::Error,
+    },
+    Parse {
+        path: PathBuf,
+        error: String,
+    },
+    /// include 대상 파일에 존재하지 않는 위치
+    MissingPointer {
+        source: String,
+    },
+    /// 자기 자신을 다시 include하는 파일
+    CyclicInclude {
+        chain: Vec<String>,
+    },
+    /// 스키마 형식에 맞지 않는 파일 또는 include 대상
+    Invalid {
+        source: String,
+        error: serde_yaml_ng::Error,
+    },
+}
+
+impl fmt::Display for LoadError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LoadError::Read { path, error } => {
+                write!(f, "failed to read {}: {error}", path.display())
+            }
+            LoadError::Parse { path, error } => {
+                write!(f, "failed to parse {}: {error}", path.display())
+            }
+            LoadError::MissingPointer { source } => {
+                write!(f, "`$ref` points to missing location `{source}`")
+            }
+            LoadError::CyclicInclude { chain } => {
+                write!(f, "cyclic `$ref` include: {}", chain.join(" -> "))
+            }
+            LoadError::Invalid { source, error } => {
+                write!(f, "failed to parse {source}: {error}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for LoadError {}
+
+impl Schema {
+    /// 스키마 파일을 읽고 다른 파일을 가리키는 `$ref` include를 해석합니다.
+    ///
+    /// 파일 형식은 확장자(`.yml`, `.yaml`, `.json`, `.toml`)로 판단하며, 경로가 `-`이면 표준 입력을 읽습니다.
+    ///
+    /// `$ref`만 가진 매핑(예: `$ref: ./entity/bank.yml#/Bank`)은 해당 파일의 내용으로 대체되며,
+    /// 상대 경로는 `$ref`가 작성된 파일을 기준으로 합니다.
+    pub fn load(path: &Path) -> Result<SchemaFile, LoadError> {
+        let mut loader = Loader {
+            files: HashMap::new(),
+            stack: vec![],
+            includes: vec![],
+        };
+        let mut value = loader.read(path)?;
+        loader.resolve(&mut value, parent_dir(path), "#")?;
+        let schema = serde_yaml_ng::from_value(value).map_err(|error| LoadError::Invalid {
+            source: path.display().to_string(),
+            error,
+        })?;
+        Ok(SchemaFile {
+            schema,
+            includes: loader.includes,
+        })
+    }
+}
+
+/// 표준 입력을 나타내는 경로
+const STDIN: &str = "-";
+
+fn parent_dir(path: &Path) -> &Path {
+    path.parent().unwrap_or(Path::new(""))
+}
+
+/// 같은 파일이 서로 다른 상대 경로로 참조되어도 같은 경로가 되도록 `.`과 `..`을 정리합니다.
+fn normalize(path: &Path) -> PathBuf {
+    let mut normalized = PathBuf::new();
+    for component in path.components() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir
+                if matches!(
+                    normalized.components().next_back(),
+                    Some(Component::Normal(_))
+                ) =>
+            {
+                normalized.pop();
+            }
+            component => normalized.push(component),
+        }
+    }
+    normalized
+}
+
+struct Loader {
+    files: HashMap<PathBuf, Value>,
+    /// 해석 중인 include 목록 (순환 include 검사용)
+    stack: Vec<String>,
+    includes: Vec<Include>,
+}
+
+impl Loader {
+    fn read(&mut self, path: &Path) -> Result<Value, LoadError> {
+        if let Some(value) = self.files.get(path) {
+            return Ok(value.clone());
+        }
+        let content = if path == Path::new(STDIN) {
+            std::io::read_to_string(std::io::stdin())
+        } else {
+            fs::read_to_string(path)
+        }
+        .map_err(|error| LoadError::Read {
+            path: path.to_path_buf(),
+            error,
+        })?;
+        let value = Format::from_path(path)
+            .parse(&content)
+            .map_err(|error| LoadError::Parse {
+                path: path.to_path_buf(),
+                error,
+            })?;
+        self.files.insert(path.to_path_buf(), value.clone());
+        Ok(value)
+    }
+
+    fn resolve(
+        &mut self,
+        value: &mut Value,
+        base_dir: &Path,
+        location: &str,
+    ) -> Result<(), LoadError> {
+        match value {
+            Value::Mapping(mapping) => {
+                if let Some(target) = include_target(mapping) {
+                    let target = target.to_string();
+                    *value = self.include(&target, base_dir, location)?;
+                    return Ok(());
+                }
+                for (key, child) in mapping.iter_mut() {
+                    let key = key.as_str().map(str::to_string).unwrap_or_default();
+                    self.resolve(child, base_dir, &format!("{location}/{key}"))?;
+                }
+            }
+            Value::Sequence(sequence) => {
+                for (i, child) in sequence.iter_mut().enumerate() {
+                    self.resolve(child, base_dir, &format!("{location}/{i}"))?;
+                }
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+
+    fn include(
+        &mut self,
+        target: &str,
+        base_dir: &Path,
+        location: &str,
+    ) -> Result<Value, LoadError> {
+        let (file, pointer) = target.split_once('#').unwrap_or((target, ""));
+        let path = normalize(&base_dir.join(file));
+        let source = if pointer.is_empty() {
+            path.display().to_string()
+        } else {
+            format!("{}#{pointer}", path.display())
+        };
+        if let Some(start) = self.stack.iter().position(|s| s == &source) {
+            let mut chain = self.stack[start..].to_vec();
+            chain.push(source);
+            return Err(LoadError::CyclicInclude { chain });
+        }
+
+        let document = self.read(&path)?;
+        let mut value = pointer
+            .split('/')
+            .filter(|segment| !segment.is_empty())
+            .try_fold(&document, |value, segment| {
+                let segment = segment.replace("~1", "/").replace("~0", "~");
+                match value {
+                    Value::Sequence(sequence) => {
+                        segment.parse::<usize>().ok().and_then(|i| sequence.get(i))
+                    }
+                    _ => value.get(segment.as_str()),
+                }
+            })
+            .ok_or_else(|| LoadError::MissingPointer {
+                source: source.clone(),
+            })?
+            .clone();
+
+        self.stack.push(source.clone());
+        self.resolve(&mut value, parent_dir(&path), location)?;
+        self.stack.pop();
+        // 병합한 뒤에는 어느 파일에서 잘못되었는지 알 수 없으므로 리소스는 include 단위로 먼저 검사
+        if location.starts_with("#/resources")
+            && let Err(error) = serde_yaml_ng::from_value::<Resource>(value.clone())
+        {
+            return Err(LoadError::Invalid { source, error });
+        }
+        self.includes.push(Include {
+            path: location.to_string(),
+            source,
+        });
+        Ok(value)
+    }
+}
+
+/// `$ref`만 가진 매핑에서 다른 파일을 가리키는 경로를 반환합니다.
+///
+/// `type: resourceRef`와 함께 쓰이는 `#/resources/...` 참조는 include가 아닙니다.
+fn include_target(mapping: &Mapping) -> Option<&str> {
+    if mapping.len() != 1 {
+        return None;
+    }
+    mapping
+        .get("$ref")
+        .and_then(Value::as_str)
+        .filter(|target| !target.starts_with('#'))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    fn write_files(name: &str, files: &[(&str, &str)]) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!(
+            "portone-client-sdk-include-{name}-{}",
+            std::process::id()
+        ));
+        for (path, content) in files {
+            let path = dir.join(path);
+            fs::create_dir_all(path.parent().unwrap()).unwrap();
+            fs::write(path, content).unwrap();
+        }
+        dir
+    }
+
+    #[test]
+    fn resolves_includes_relative_to_each_file() {
+        let dir = write_files(
+            "resolve",
+            &[
+                (
+                    "schema.yml",
+                    "flags: {}\nresources:\n  entity:\n    $ref: ./entity/index.yml\nmethods: {}\n",
+                ),
+                (
+                    "entity/index.yml",
+                    "Bank:\n  $ref: ./bank.yml#/Bank\nCountry:\n  type: string\n",
+                ),
+                (
+                    "entity/bank.yml",
+                    "Bank:\n  type: enum\n  variants:\n    KAKAO: {}\n",
+                ),
+            ],
+        );
+        let file = Schema::load(&dir.join("schema.yml")).unwrap();
+        fs::remove_dir_all(&dir).unwrap();
+
+        let index = file.schema.build_resource_index();
+        assert_eq!(
+            index.keys().collect::<Vec<_>>(),
+            vec!["entity/Bank", "entity/Country"]
+        );
+        let source = |path| file.source_of(path).map(|include| include.path.as_str());
+        assert_eq!(
+            source("#/resources/entity/Bank/variants/KAKAO"),
+            Some("#/resources/entity/Bank")
+        );
+        assert_eq!(
+            source("#/resources/entity/Country"),
+            Some("#/resources/entity")
+        );
+        assert_eq!(source("#/resources/entityList"), None);
+    }
+
+    #[test]
+    fn reports_missing_pointer_and_cyclic_include() {
+        let dir = write_files(
+            "errors",
+            &[
+                (
+                    "missing.yml",
+                    "flags: {}\nresources:\n  $ref: ./a.yml#/Missing\nmethods: {}\n",
+                ),
+                (
+                    "cyclic.yml",
+                    "flags: {}\nresources:\n  $ref: ./a.yml\nmethods: {}\n",
+                ),
+                ("a.yml", "A:\n  $ref: ./b.yml\n"),
+                ("b.yml", "B:\n  $ref: ./a.yml\n"),
+            ],
+        );
+        let missing = Schema::load(&dir.join("missing.yml")).unwrap_err();
+        let cyclic = Schema::load(&dir.join("cyclic.yml")).unwrap_err();
+        fs::remove_dir_all(&dir).unwrap();
+
+        assert!(
+            matches!(missing, LoadError::MissingPointer { source } if source.ends_with("a.yml#/Missing"))
+        );
+        let LoadError::CyclicInclude { chain } = cyclic else {
+            panic!("expected cyclic include, got {cyclic:?}");
+        };
+        assert_eq!(chain.len(), 3);
+        assert_eq!(chain[0], chain[2]);
+        assert!(chain[0].ends_with("a.yml"));
+    }
+}
@@ -0,0 +1,243 @@
+use serde_json::{Map, Value, json};
+
+use crate::{Parameter, ParameterType, Schema};
+
+const JSON_SCHEMA_DIALECT: &str = "https://json-schema.org/draft/2020-12/schema";
+const DECIMAL_PATTERN: &str = r"^-?\d+(?:\.\d+)?$";
+
+impl Schema {
+    /// 각 리소스가 나타내는 값의 JSON Schema를 리소스 경로(예: `entity/Bank`)를 키로 하는 `$defs`에 담아 반환합니다.
+    pub fn to_json_schema(&self) -> Value {
+        let defs: Map<String, Value> = self
+            .build_resource_index()
+            .iter()
+            .map(|(path, parameter)| (path.clone(), parameter_schema(parameter)))
+            .collect();
+        json!({
+            "$schema": JSON_SCHEMA_DIALECT,
+            "$defs": defs,
+        })
+    }
+}
+
+fn parameter_schema(parameter: &Parameter) -> Value {
+    let mut schema = type_schema(&parameter.r#type);
+    if let Some(object) = schema.as_object_mut() {
+        if let Some(description) = &parameter.description {
+            object.insert("description".to_string(), json!(description));
+        }
+        if parameter.deprecated {
+            object.insert("deprecated".to_string(), json!(true));
+        }
+        if let Some(default) = &parameter.default {
+            object.insert("default".to_string(), default.as_ref().clone());
+        }
+    }
+    schema
+}
+
+fn type_schema(r#type: &ParameterType) -> Value {
+    match r#type {
+        ParameterType::String {
+            pattern,
+            min_length,
+            max_length,
+            format,
+        } => {
+            let mut schema = json!({ "type": "string" });
+            if let Some(pattern) = pattern {
+                schema["pattern"] = json!(pattern);
+            }
+            if let Some(min_length) = min_length {
+                schema["minLength"] = json!(min_length);
+            }
+            if let Some(max_length) = max_length {
+                schema["maxLength"] = json!(max_length);
+            }
+            if let Some(format) = format {
+                schema["format"] = json!(format.to_string());
+            }
+            schema
+        }
+        ParameterType::StringLiteral { value } => json!({ "const": value }),
+        ParameterType::Integer => json!({ "type": "integer" }),
+        ParameterType::Number { minimum, maximum } => {
+            let mut schema = json!({ "type": "number" });
+            if let Some(minimum) = minimum {
+                schema["minimum"] = json!(minimum);
+            }
+            if let Some(maximum) = maximum {
+                schema["maximum"] = json!(maximum);
+            }
+            schema
+        }
+        // 문자열 범위는 JSON Schema로 표현할 수 없으므로 형식만 검사
+        ParameterType::Decimal { .. } => json!({
+            "type": "string",
+            "pattern": DECIMAL_PATTERN,
+        }),
+        ParameterType::Boolean => json!({ "type": "boolean" }),
+        ParameterType::Array { items, .. } => json!({
+            "type": "array",
+            "items": parameter_schema(items),
+        }),
+        ParameterType::Map { values } => json!({
+            "type": "object",
+            "additionalProperties": parameter_schema(values),
+        }),
+        ParameterType::Object { properties, .. } | ParameterType::Error { properties, .. } => {
+            let required: Vec<_> = properties
+                .iter()
+                .filter(|(_, property)| !property.optional)
+                .map(|(name, _)| name)
+                .collect();
+            let properties: Map<_, _> = properties
+                .iter()
+                .map(|(name, property)| (name.clone(), parameter_schema(property)))
+                .collect();
+            json!({
+                "type": "object",
+                "properties": properties,
+                "required": required,
+            })
+        }
+        ParameterType::EmptyObject => json!({ "type": "object", "maxProperties": 0 }),
+        ParameterType::Enum {
+            variants,
+            value_prefix,
+        } => match value_prefix {
+            // `PREFIX_VALUE` 형태도 허용
+            Some(value_prefix) => {
+                let values: Vec<_> = variants.keys().map(|value| escape_regex(value)).collect();
+                json!({
+                    "type": "string",
+                    "pattern": format!(
+                        "^(?:{}_)?(?:{})$",
+                        escape_regex(value_prefix),
+                        values.join("|")
+                    ),
+                })
+            }
+            None => json!({
+                "type": "string",
+                "enum": variants.keys().collect::<Vec<_>>(),
+            }),
+        },
+        // 프로퍼티 중 정확히 하나만 존재해야 함
+        ParameterType::OneOf { properties, .. } => {
+            let choices: Vec<_> = properties
+                .keys()
+                .map(|name| json!({ "required": [name] }))
+                .collect();
+            let properties: Map<_, _> = properties
+                .iter()
+                .map(|(name, property)| (name.clone(), parameter_schema(property)))
+                .collect();
+            json!({
+                "type": "object",
+                "properties": properties,
+                "oneOf": choices,
+            })
+        }
+        ParameterType::Union { types, .. } => json!({
+            "anyOf": types.iter().map(parameter_schema).collect::<Vec<_>>(),
+        }),
+        ParameterType::Intersection { types, .. } => json!({
+            "allOf": types.iter().map(parameter_schema).collect::<Vec<_>>(),
+        }),
+        ParameterType::ResourceRef(resource_ref) => json!({
+            "$ref": format!("#/$defs/{}", resource_ref.resource_ref().replace('~', "~0").replace('/', "~1")),
+        }),
+        ParameterType::Json => json!({ "type": "object" }),
+    }
+}
+
+fn escape_regex(value: &str) -> String {
+    value
+        .chars()
+        .flat_map(|c| {
+            let escape = "\\.+*?()|[]{}^$".contains(c).then_some('\\');
+            escape.into_iter().chain([c])
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn exports_resources_as_defs() {
+        let schema: Schema = serde_yaml_ng::from_str(
+            r##"
+flags: {}
+resources:
+  entity:
+    Bank:
+      type: enum
+      valuePrefix: BANK
+      variants:
+        KAKAO: {}
+        TOSS: {}
+    Method:
+      type: oneOf
+      description: 결제수단
+      properties:
+        card:
+          type: string
+        bank:
+          type: resourceRef
+          $ref: '#/resources/entity/Bank'
+    Request:
+      type: intersection
+      types:
+        - type: resourceRef
+          $ref: '#/resources/entity/Method'
+        - type: object
+          properties:
+            amount:
+              type: integer
+            memo:
+              type: string
+              optional: true
+methods: {}
+"##,
+        )
+        .unwrap();
+        assert_eq!(
+            schema.to_json_schema(),
+            json!({
+                "$schema": JSON_SCHEMA_DIALECT,
+                "$defs": {
+                    "entity/Bank": {
+                        "type": "string",
+                        "pattern": "^(?:BANK_)?(?:KAKAO|TOSS)$",
+                    },
+                    "entity/Method": {
+                        "type": "object",
+                        "description": "결제수단",
+                        "properties": {
+                            "card": { "type": "string" },
+                            "bank": { "$ref": "#/$defs/entity~1Bank" },
+                        },
+                        "oneOf": [{ "required": ["card"] }, { "required": ["bank"] }],
+                    },
+                    "entity/Request": {
+                        "allOf": [
+                            { "$ref": "#/$defs/entity~1Method" },
+                            {
+                                "type": "object",
+                                "properties": {
+                                    "amount": { "type": "integer" },
+                                    "memo": { "type": "string" },
+                                },
+                                "required": ["amount"],
+                            },
+                        ],
+                    },
+                },
+            })
+        );
+    }
+}
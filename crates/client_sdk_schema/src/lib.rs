@@ -1,12 +1,35 @@
+use std::fmt;
+
 use indexmap::IndexMap;
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
+mod diff;
+mod filter;
+mod format;
+mod hoist;
+mod include;
+mod json_schema;
+mod mobile;
+mod openapi;
+mod validate;
+mod version;
+
+pub use diff::*;
+pub use include::*;
+pub use mobile::*;
+pub use openapi::OpenApiImport;
+pub use validate::*;
+pub use version::Version;
+
 better_scoped_tls::scoped_tls!(pub static RESOURCE_INDEX: IndexMap<String, Parameter>);
 
-#[derive(Serialize, Deserialize, Debug, PartialEq, JsonSchema)]
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct Schema {
+    /// 스키마 버전
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub version: Option<Version>,
     /// 플래그 목록
     pub flags: IndexMap<String, Flag>,
     /// 리소스 목록
@@ -48,7 +71,7 @@ pub enum Resource {
     Parameter(Parameter),
 }
 
-#[derive(Serialize, Deserialize, Debug, PartialEq, JsonSchema)]
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct Flag {
     /// 플래그 설명
@@ -62,6 +85,8 @@ pub trait ParameterExt {
     fn optional(&self) -> bool;
     fn flag_options(&self) -> Option<&IndexMap<String, FlagOption>>;
     fn deprecated(&self) -> bool;
+    fn hide_if_empty(&self) -> bool;
+    fn default(&self) -> Option<&serde_json::Value>;
 }
 
 impl ParameterExt for Parameter {
@@ -118,6 +143,27 @@ impl ParameterExt for Parameter {
             _ => false,
         }
     }
+
+    fn hide_if_empty(&self) -> bool {
+        match &self.r#type {
+            ParameterType::Array { hide_if_empty, .. }
+            | ParameterType::Object { hide_if_empty, .. }
+            | ParameterType::OneOf { hide_if_empty, .. }
+            | ParameterType::Union { hide_if_empty, .. }
+            | ParameterType::Intersection { hide_if_empty, .. } => *hide_if_empty,
+            ParameterType::ResourceRef(resource_ref) => RESOURCE_INDEX.with(|index| {
+                index
+                    .get(resource_ref.resource_ref())
+                    .map(|parameter| parameter.hide_if_empty())
+                    .unwrap_or(false)
+            }),
+            _ => false,
+        }
+    }
+
+    fn default(&self) -> Option<&serde_json::Value> {
+        self.default.as_deref()
+    }
 }
 
 #[derive(Default, Serialize, Deserialize, Debug, PartialEq, Clone, JsonSchema)]
@@ -139,6 +185,16 @@ pub struct Parameter {
     /// Deprecated 여부
     #[serde(default, skip_serializing_if = "std::ops::Not::not")]
     pub deprecated: bool,
+    /// 처음 추가된 버전
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub since: Option<Version>,
+    /// 제거된 버전
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub removed_in: Option<Version>,
+    /// 값을 지정하지 않았을 때 사용되는 기본값
+    // `Resource`의 크기를 줄이기 위해 `Box`로 저장
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub default: Option<Box<serde_json::Value>>,
 }
 
 impl Parameter {
@@ -157,6 +213,9 @@ impl Parameter {
             optional,
             flag_options,
             deprecated,
+            since: None,
+            removed_in: None,
+            default: None,
         }
     }
 }
@@ -169,12 +228,36 @@ pub struct FlagOption {
     visible: bool,
 }
 
+impl FlagOption {
+    pub fn new(visible: bool) -> Self {
+        Self { visible }
+    }
+
+    pub fn visible(&self) -> bool {
+        self.visible
+    }
+}
+
 #[derive(Serialize, Deserialize, Debug, Default, PartialEq, Clone, JsonSchema)]
 #[serde(tag = "type", rename_all = "camelCase")]
 /// 파라미터 타입
 pub enum ParameterType {
     #[schemars(title = "string")]
-    String,
+    #[serde(rename_all = "camelCase")]
+    String {
+        /// 값이 만족해야 하는 정규식
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pattern: Option<String>,
+        /// 최소 길이
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        min_length: Option<u32>,
+        /// 최대 길이
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        max_length: Option<u32>,
+        /// 값의 형식
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        format: Option<StringFormat>,
+    },
     #[schemars(title = "stringLiteral")]
     StringLiteral {
         /// StringLiteral의 값
@@ -182,6 +265,26 @@ pub enum ParameterType {
     },
     #[schemars(title = "integer")]
     Integer,
+    /// 부동소수점 숫자
+    #[schemars(title = "number")]
+    Number {
+        /// 허용되는 최솟값 (포함)
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        minimum: Option<f64>,
+        /// 허용되는 최댓값 (포함)
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        maximum: Option<f64>,
+    },
+    /// 정밀도 손실을 막기 위해 문자열로 전달되는 십진수 (예: `"1234.56"`)
+    #[schemars(title = "decimal")]
+    Decimal {
+        /// 허용되는 최솟값 (포함)
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        minimum: Option<f64>,
+        /// 허용되는 최댓값 (포함)
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        maximum: Option<f64>,
+    },
     #[schemars(title = "boolean")]
     Boolean,
     #[schemars(title = "array")]
@@ -192,6 +295,12 @@ pub enum ParameterType {
         #[serde(default, skip_serializing_if = "std::ops::Not::not")]
         hide_if_empty: bool,
     },
+    /// 문자열 키와 같은 타입의 값으로 이루어진 맵
+    #[schemars(title = "map")]
+    Map {
+        /// Map의 값 타입
+        values: Box<Parameter>,
+    },
     #[schemars(title = "object")]
     #[serde(rename_all = "camelCase")]
     Object {
@@ -253,6 +362,76 @@ pub enum ParameterType {
     Json,
 }
 
+impl ParameterType {
+    /// 제약 조건이 없는 문자열 타입
+    pub const fn string() -> Self {
+        ParameterType::String {
+            pattern: None,
+            min_length: None,
+            max_length: None,
+            format: None,
+        }
+    }
+
+    /// 문서 주석에 표시할 값의 제약 조건 목록을 반환합니다. (예: ``최솟값: `0` ``)
+    pub fn constraints(&self) -> Vec<String> {
+        let mut constraints = vec![];
+        if let ParameterType::String {
+            pattern,
+            min_length,
+            max_length,
+            format,
+        } = self
+        {
+            if let Some(format) = format {
+                constraints.push(format!("형식: `{format}`"));
+            }
+            if let Some(pattern) = pattern {
+                constraints.push(format!("패턴: `{pattern}`"));
+            }
+            if let Some(min_length) = min_length {
+                constraints.push(format!("최소 길이: `{min_length}`"));
+            }
+            if let Some(max_length) = max_length {
+                constraints.push(format!("최대 길이: `{max_length}`"));
+            }
+        }
+        if let ParameterType::Number { minimum, maximum }
+        | ParameterType::Decimal { minimum, maximum } = self
+        {
+            if let Some(minimum) = minimum {
+                constraints.push(format!("최솟값: `{minimum}`"));
+            }
+            if let Some(maximum) = maximum {
+                constraints.push(format!("최댓값: `{maximum}`"));
+            }
+        }
+        constraints
+    }
+}
+
+/// 문자열 값의 형식
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone, Copy, JsonSchema)]
+#[serde(rename_all = "kebab-case")]
+pub enum StringFormat {
+    Email,
+    Uri,
+    DateTime,
+    /// 전화번호 (숫자와 `-`, 국가 번호 앞의 `+`)
+    Phone,
+}
+
+impl fmt::Display for StringFormat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            StringFormat::Email => write!(f, "email"),
+            StringFormat::Uri => write!(f, "uri"),
+            StringFormat::DateTime => write!(f, "date-time"),
+            StringFormat::Phone => write!(f, "phone"),
+        }
+    }
+}
+
 #[derive(Serialize, Deserialize, Debug, PartialEq, Clone, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct ResourceRef {
@@ -280,6 +459,21 @@ pub struct EnumVariant {
     pub description: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub alias: Option<String>,
+    /// 플래그 옵션
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub flag_options: Option<IndexMap<String, FlagOption>>,
+    /// Deprecated 여부
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub deprecated: bool,
+    /// Deprecated일 때 대신 사용할 항목
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub replacement: Option<String>,
+    /// 처음 추가된 버전
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub since: Option<Version>,
+    /// 제거된 버전
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub removed_in: Option<Version>,
 }
 
 #[derive(Serialize, Deserialize, Debug, PartialEq, Clone, JsonSchema)]
@@ -294,6 +488,12 @@ pub struct Method {
     pub callbacks: Option<IndexMap<String, Callback>>,
     /// 메소드 출력 파라미터
     pub output: Option<Parameter>,
+    /// Deprecated 여부
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub deprecated: bool,
+    /// Deprecated일 때 대신 사용할 항목
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub replacement: Option<String>,
 }
 
 #[derive(Serialize, Deserialize, Debug, PartialEq, Clone, JsonSchema)]
@@ -304,8 +504,38 @@ pub struct Callback {
     pub description: Option<String>,
     /// 콜백 입력 파라미터
     pub input: IndexMap<String, Parameter>,
+    /// Deprecated 여부
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub deprecated: bool,
+    /// Deprecated일 때 대신 사용할 항목
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub replacement: Option<String>,
+}
+
+/// 열거형 값, 메소드, 콜백의 deprecation 정보
+pub trait DeprecationExt {
+    fn deprecated(&self) -> bool;
+    fn replacement(&self) -> Option<&str>;
+}
+
+macro_rules! impl_deprecation_ext {
+    ($($ty:ty),*) => {
+        $(
+            impl DeprecationExt for $ty {
+                fn deprecated(&self) -> bool {
+                    self.deprecated
+                }
+
+                fn replacement(&self) -> Option<&str> {
+                    self.replacement.as_deref()
+                }
+            }
+        )*
+    };
 }
 
+impl_deprecation_ext!(EnumVariant, Method, Callback);
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -360,10 +590,13 @@ mod tests {
                             Parameter {
                                 name: None,
                                 description: Some("The person's name".to_string()),
-                                r#type: ParameterType::String,
+                                r#type: ParameterType::string(),
                                 optional: false,
                                 flag_options: None,
                                 deprecated: false,
+                                since: None,
+                                removed_in: None,
+                                default: None,
                             },
                         );
                         properties.insert(
@@ -375,6 +608,9 @@ mod tests {
                                 optional: true,
                                 flag_options: None,
                                 deprecated: false,
+                                since: None,
+                                removed_in: None,
+                                default: None,
                             },
                         );
                         properties
@@ -383,6 +619,9 @@ mod tests {
                 optional: false,
                 flag_options: None,
                 deprecated: false,
+                since: None,
+                removed_in: None,
+                default: None,
             },
         );
         parameters.insert(
@@ -398,6 +637,11 @@ mod tests {
                             EnumVariant {
                                 description: Some("Red color".to_string()),
                                 alias: None,
+                                flag_options: None,
+                                deprecated: false,
+                                replacement: None,
+                                since: None,
+                                removed_in: None,
                             },
                         );
                         variants.insert(
@@ -405,6 +649,11 @@ mod tests {
                             EnumVariant {
                                 description: Some("Green color".to_string()),
                                 alias: None,
+                                flag_options: None,
+                                deprecated: false,
+                                replacement: None,
+                                since: None,
+                                removed_in: None,
                             },
                         );
                         variants.insert(
@@ -412,6 +661,11 @@ mod tests {
                             EnumVariant {
                                 description: Some("Blue color".to_string()),
                                 alias: Some("Aqua".to_string()),
+                                flag_options: None,
+                                deprecated: false,
+                                replacement: None,
+                                since: None,
+                                removed_in: None,
                             },
                         );
                         variants
@@ -421,6 +675,9 @@ mod tests {
                 optional: false,
                 flag_options: None,
                 deprecated: false,
+                since: None,
+                removed_in: None,
+                default: None,
             },
         );
         parameters.insert(
@@ -436,12 +693,18 @@ mod tests {
                         optional: false,
                         flag_options: None,
                         deprecated: false,
+                        since: None,
+                        removed_in: None,
+                        default: None,
                     }),
                     hide_if_empty: false,
                 },
                 optional: false,
                 flag_options: None,
                 deprecated: false,
+                since: None,
+                removed_in: None,
+                default: None,
             },
         );
         parameters.insert(
@@ -453,6 +716,9 @@ mod tests {
                 optional: false,
                 flag_options: None,
                 deprecated: false,
+                since: None,
+                removed_in: None,
+                default: None,
             },
         );
         parameters.insert(
@@ -466,6 +732,9 @@ mod tests {
                 optional: false,
                 flag_options: None,
                 deprecated: false,
+                since: None,
+                removed_in: None,
+                default: None,
             },
         );
 
@@ -478,6 +747,7 @@ mod tests {
                     .collect(),
             ),
             methods: IndexMap::new(),
+            version: None,
         };
 
         // Serialize the schema to YAML
@@ -543,4 +813,29 @@ methods: {}
         // Compare the deserialized schema with the original schema
         assert_eq!(deserialized, schema);
     }
+
+    #[test]
+    fn test_deprecation_deserialization() {
+        let method: Method = serde_yaml_ng::from_str(
+            "
+input:
+  type: string
+deprecated: true
+replacement: requestPayment
+callbacks:
+  onDone:
+    input: {}
+    deprecated: true
+",
+        )
+        .unwrap();
+        assert!(method.deprecated());
+        assert_eq!(method.replacement(), Some("requestPayment"));
+        let callback = &method.callbacks.as_ref().unwrap()["onDone"];
+        assert!(callback.deprecated());
+        assert_eq!(callback.replacement(), None);
+
+        let variant: EnumVariant = serde_yaml_ng::from_str("description: 토스페이").unwrap();
+        assert!(!variant.deprecated());
+    }
 }
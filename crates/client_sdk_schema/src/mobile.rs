@@ -0,0 +1,189 @@
+use indexmap::IndexMap;
+
+use crate::{Method, Parameter, ParameterType, Resource, Schema};
+
+/// 모바일 코드젠(Kotlin, Swift, Dart)이 처리하는 리소스 최상위 디렉토리
+pub const MOBILE_RESOURCE_ROOTS: [&str; 4] = ["entity", "request", "response", "exception"];
+
+/// 리소스 경로(예: `entity/Bank`)가 모바일 코드젠이 생성하는 리소스인지 확인합니다.
+pub fn is_mobile_resource(resource_path: &str) -> bool {
+    resource_path
+        .split('/')
+        .next()
+        .is_some_and(|root| MOBILE_RESOURCE_ROOTS.contains(&root))
+}
+
+/// 파라미터의 타입이 모바일 SDK에서 표현 가능한지 확인합니다.
+///
+/// 인라인 객체는 리소스 안에 있을 때만 이름이 있는 타입으로 분리되므로 여기서는 표현할 수 없는 것으로 봅니다.
+pub fn is_mobile_type(parameter: &Parameter, index: &IndexMap<String, Parameter>) -> bool {
+    match &parameter.r#type {
+        ParameterType::String { .. }
+        | ParameterType::StringLiteral { .. }
+        | ParameterType::Integer
+        | ParameterType::Number { .. }
+        | ParameterType::Decimal { .. }
+        | ParameterType::Boolean
+        | ParameterType::Json
+        | ParameterType::Enum { .. }
+        | ParameterType::Error { .. } => true,
+        ParameterType::Array { items, .. } | ParameterType::Map { values: items } => {
+            match &items.r#type {
+                ParameterType::String { .. }
+                | ParameterType::StringLiteral { .. }
+                | ParameterType::Integer
+                | ParameterType::Number { .. }
+                | ParameterType::Decimal { .. }
+                | ParameterType::Boolean
+                | ParameterType::Json
+                | ParameterType::Enum { .. } => true,
+                ParameterType::ResourceRef(resource_ref) => {
+                    is_mobile_resource(resource_ref.resource_ref())
+                }
+                ParameterType::Array { .. } | ParameterType::Map { .. } => {
+                    is_mobile_type(items, index)
+                }
+                _ => false,
+            }
+        }
+        ParameterType::ResourceRef(resource_ref) => match index.get(resource_ref.resource_ref()) {
+            Some(parameter) => match &parameter.r#type {
+                ParameterType::Object { .. }
+                | ParameterType::EmptyObject
+                | ParameterType::Enum { .. }
+                | ParameterType::Union { .. }
+                | ParameterType::OneOf { .. }
+                | ParameterType::Intersection { .. } => {
+                    is_mobile_resource(resource_ref.resource_ref())
+                }
+                _ => is_mobile_type(parameter, index),
+            },
+            None => false,
+        },
+        _ => false,
+    }
+}
+
+impl Method {
+    /// 모바일 SDK에서 표현할 수 없는 타입을 사용하는 첫 파라미터의 메소드 안 경로
+    /// (예: `input`, `callbacks/onError/input/error`)를 반환합니다.
+    ///
+    /// 이러한 메소드는 모바일 SDK의 `PortOne` 타입에서 제외됩니다.
+    pub fn find_unsupported_mobile_parameter(
+        &self,
+        index: &IndexMap<String, Parameter>,
+    ) -> Option<String> {
+        let callback_parameters = self
+            .callbacks
+            .iter()
+            .flatten()
+            .flat_map(|(name, callback)| {
+                callback
+                    .input
+                    .iter()
+                    .map(move |(parameter_name, parameter)| {
+                        (
+                            format!("callbacks/{name}/input/{parameter_name}"),
+                            parameter,
+                        )
+                    })
+            });
+        std::iter::once(("input".to_string(), &self.input))
+            .chain(
+                self.output
+                    .as_ref()
+                    .map(|output| ("output".to_string(), output)),
+            )
+            .chain(callback_parameters)
+            .find(|(_, parameter)| !is_mobile_type(parameter, index))
+            .map(|(path, _)| path)
+    }
+}
+
+impl Schema {
+    /// 모바일 코드젠과 같이 인라인 타입을 분리한 뒤, 모바일 SDK에서 생성되지 않는 메소드와
+    /// [`MOBILE_RESOURCE_ROOTS`] 밖의 리소스를 제거합니다.
+    pub fn retain_mobile(&mut self) {
+        self.hoist_inline_types();
+        let index = self.build_resource_index();
+        self.methods
+            .retain(|_, method| method.find_unsupported_mobile_parameter(&index).is_none());
+        if let Resource::SubResources(resources) = &mut self.resources {
+            resources.retain(|root, _| MOBILE_RESOURCE_ROOTS.contains(&root.as_str()));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Callback, ResourceRef};
+    use pretty_assertions::assert_eq;
+
+    fn parameter(r#type: ParameterType) -> Parameter {
+        Parameter {
+            r#type,
+            ..Default::default()
+        }
+    }
+
+    fn resource_ref(path: &str) -> Parameter {
+        parameter(ParameterType::ResourceRef(ResourceRef::new(path)))
+    }
+
+    #[test]
+    fn finds_unsupported_mobile_parameter() {
+        let object = parameter(ParameterType::Object {
+            properties: IndexMap::new(),
+            hide_if_empty: false,
+        });
+        let index = IndexMap::from([
+            ("request/PaymentRequest".to_string(), object.clone()),
+            ("misc/Internal".to_string(), object.clone()),
+            (
+                "entity/Codes".to_string(),
+                parameter(ParameterType::Array {
+                    items: Box::new(parameter(ParameterType::string())),
+                    hide_if_empty: false,
+                }),
+            ),
+        ]);
+        let method = |input: Parameter, callback_input: Parameter| Method {
+            description: None,
+            input,
+            callbacks: Some(IndexMap::from([(
+                "onError".to_string(),
+                Callback {
+                    description: None,
+                    input: IndexMap::from([("error".to_string(), callback_input)]),
+                    deprecated: false,
+                    replacement: None,
+                },
+            )])),
+            output: None,
+            deprecated: false,
+            replacement: None,
+        };
+
+        let supported = method(
+            resource_ref("#/resources/request/PaymentRequest"),
+            resource_ref("#/resources/entity/Codes"),
+        );
+        assert_eq!(supported.find_unsupported_mobile_parameter(&index), None);
+
+        let inline_input = method(object, resource_ref("#/resources/entity/Codes"));
+        assert_eq!(
+            inline_input.find_unsupported_mobile_parameter(&index),
+            Some("input".to_string())
+        );
+
+        let outside_roots = method(
+            resource_ref("#/resources/request/PaymentRequest"),
+            resource_ref("#/resources/misc/Internal"),
+        );
+        assert_eq!(
+            outside_roots.find_unsupported_mobile_parameter(&index),
+            Some("callbacks/onError/input/error".to_string())
+        );
+    }
+}
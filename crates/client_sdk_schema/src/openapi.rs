@@ -0,0 +1,422 @@
+use indexmap::IndexMap;
+use serde_yaml_ng::Value;
+
+use crate::{EnumVariant, Parameter, ParameterType, Resource, ResourceRef, Schema, StringFormat};
+
+const COMPONENTS_PREFIX: &str = "#/components/schemas/";
+
+/// OpenAPI 문서에서 가져온 스키마
+#[derive(Debug)]
+pub struct OpenApiImport {
+    pub schema: Schema,
+    /// 정확히 대응되는 타입이 없어 근사한 항목 (예: `#/components/schemas/Rate: ...`)
+    pub warnings: Vec<String>,
+}
+
+impl Schema {
+    /// OpenAPI 3 문서의 `components/schemas`를 `resources/{resource_dir}` 아래의 리소스로 변환합니다.
+    ///
+    /// `$ref`는 같은 디렉토리의 `resourceRef`로, `oneOf`/`anyOf`는 union으로, `allOf`는 intersection으로 변환됩니다.
+    pub fn from_openapi(document: &Value, resource_dir: &str) -> Result<OpenApiImport, String> {
+        let schemas = document
+            .get("components")
+            .and_then(|components| components.get("schemas"))
+            .and_then(Value::as_mapping)
+            .ok_or("OpenAPI document has no `components/schemas`")?;
+
+        let mut importer = Importer {
+            resource_dir,
+            warnings: vec![],
+        };
+        let mut resources = IndexMap::new();
+        for (name, schema) in schemas {
+            let Some(name) = name.as_str() else {
+                continue;
+            };
+            let parameter = importer.import(schema, &format!("{COMPONENTS_PREFIX}{name}"));
+            resources.insert(name.to_string(), Resource::Parameter(parameter));
+        }
+
+        Ok(OpenApiImport {
+            schema: Schema {
+                version: None,
+                flags: IndexMap::new(),
+                resources: Resource::SubResources(IndexMap::from([(
+                    resource_dir.to_string(),
+                    Resource::SubResources(resources),
+                )])),
+                methods: IndexMap::new(),
+            },
+            warnings: importer.warnings,
+        })
+    }
+}
+
+struct Importer<'a> {
+    resource_dir: &'a str,
+    warnings: Vec<String>,
+}
+
+impl Importer<'_> {
+    fn warn(&mut self, path: &str, message: impl std::fmt::Display) {
+        self.warnings.push(format!("{path}: {message}"));
+    }
+
+    fn import(&mut self, schema: &Value, path: &str) -> Parameter {
+        let description = schema
+            .get("description")
+            .and_then(Value::as_str)
+            .map(str::to_string);
+        let deprecated = schema
+            .get("deprecated")
+            .and_then(Value::as_bool)
+            .unwrap_or(false);
+        let default = schema
+            .get("default")
+            .and_then(|default| serde_json::to_value(default).ok())
+            .map(Box::new);
+        if schema.get("nullable").and_then(Value::as_bool) == Some(true) {
+            self.warn(path, "`nullable` is ignored");
+        }
+        let mut parameter = Parameter {
+            description,
+            r#type: self.import_type(schema, path),
+            deprecated,
+            default,
+            ..Default::default()
+        };
+        // 설명을 붙이기 위해 `allOf`로 감싼 단일 참조는 참조 자체로 취급
+        if let ParameterType::Intersection { types, .. } = &mut parameter.r#type
+            && types.len() == 1
+        {
+            let member = types.pop().unwrap();
+            parameter.r#type = member.r#type;
+            parameter.description = parameter.description.or(member.description);
+        }
+        parameter
+    }
+
+    fn import_type(&mut self, schema: &Value, path: &str) -> ParameterType {
+        if let Some(reference) = schema.get("$ref").and_then(Value::as_str) {
+            match reference.strip_prefix(COMPONENTS_PREFIX) {
+                Some(name) => ParameterType::ResourceRef(ResourceRef::new(&format!(
+                    "#/resources/{}/{name}",
+                    self.resource_dir
+                ))),
+                None => {
+                    self.warn(path, format!("unsupported `$ref` `{reference}`"));
+                    ParameterType::Json
+                }
+            }
+        } else if let Some(members) = schema.get("oneOf").or_else(|| schema.get("anyOf")) {
+            ParameterType::Union {
+                types: self.import_members(members, path),
+                hide_if_empty: false,
+            }
+        } else if let Some(members) = schema.get("allOf") {
+            ParameterType::Intersection {
+                types: self.import_members(members, path),
+                hide_if_empty: false,
+            }
+        } else if let Some(value) = schema.get("const").and_then(Value::as_str) {
+            ParameterType::StringLiteral {
+                value: value.to_string(),
+            }
+        } else if let Some(values) = schema.get("enum").and_then(Value::as_sequence) {
+            self.import_enum(values, path)
+        } else {
+            match schema.get("type").and_then(Value::as_str) {
+                Some("string") => ParameterType::String {
+                    pattern: schema
+                        .get("pattern")
+                        .and_then(Value::as_str)
+                        .map(str::to_string),
+                    min_length: length(schema, "minLength"),
+                    max_length: length(schema, "maxLength"),
+                    format: schema
+                        .get("format")
+                        .and_then(Value::as_str)
+                        .and_then(string_format),
+                },
+                Some("integer") => ParameterType::Integer,
+                Some("number") => ParameterType::Number {
+                    minimum: schema.get("minimum").and_then(Value::as_f64),
+                    maximum: schema.get("maximum").and_then(Value::as_f64),
+                },
+                Some("boolean") => ParameterType::Boolean,
+                Some("array") => ParameterType::Array {
+                    items: Box::new(match schema.get("items") {
+                        Some(items) => self.import(items, &format!("{path}/items")),
+                        None => Parameter::default(),
+                    }),
+                    hide_if_empty: false,
+                },
+                Some("object") | None if schema.get("properties").is_some() => {
+                    self.import_object(schema, path)
+                }
+                Some("object") => match schema.get("additionalProperties") {
+                    Some(values) if values.is_mapping() => ParameterType::Map {
+                        values: Box::new(
+                            self.import(values, &format!("{path}/additionalProperties")),
+                        ),
+                    },
+                    None | Some(Value::Bool(false)) => ParameterType::EmptyObject,
+                    // `additionalProperties: true`는 값 타입을 알 수 없음
+                    Some(_) => ParameterType::Json,
+                },
+                r#type => {
+                    let r#type = r#type.unwrap_or("(none)");
+                    self.warn(path, format!("type `{type}` is imported as `json`"));
+                    ParameterType::Json
+                }
+            }
+        }
+    }
+
+    /// 문자열 값만 enum으로 가져오고, 다른 값이 섞여 있으면 `integer` 또는 `json`으로 가져옵니다.
+    fn import_enum(&mut self, values: &[Value], path: &str) -> ParameterType {
+        if values.iter().any(Value::is_null) {
+            self.warn(path, "`null` enum value is ignored");
+        }
+        let values: Vec<_> = values.iter().filter(|value| !value.is_null()).collect();
+        if let Some(values) = values
+            .iter()
+            .map(|value| value.as_str())
+            .collect::<Option<Vec<_>>>()
+        {
+            return ParameterType::Enum {
+                variants: values
+                    .into_iter()
+                    .map(|value| {
+                        let variant = EnumVariant {
+                            description: None,
+                            alias: None,
+                            flag_options: None,
+                            deprecated: false,
+                            replacement: None,
+                            since: None,
+                            removed_in: None,
+                        };
+                        (value.to_string(), variant)
+                    })
+                    .collect(),
+                value_prefix: None,
+            };
+        }
+        let (r#type, type_name) = if values.iter().all(|value| value.is_i64() || value.is_u64()) {
+            (ParameterType::Integer, "integer")
+        } else {
+            (ParameterType::Json, "json")
+        };
+        self.warn(
+            path,
+            format!("enum with non-string values is imported as `{type_name}`"),
+        );
+        r#type
+    }
+
+    fn import_object(&mut self, schema: &Value, path: &str) -> ParameterType {
+        let required: Vec<_> = schema
+            .get("required")
+            .and_then(Value::as_sequence)
+            .into_iter()
+            .flatten()
+            .filter_map(Value::as_str)
+            .collect();
+        let properties = schema
+            .get("properties")
+            .and_then(Value::as_mapping)
+            .into_iter()
+            .flatten()
+            .filter_map(|(name, property)| {
+                let name = name.as_str()?;
+                let mut parameter = self.import(property, &format!("{path}/properties/{name}"));
+                parameter.optional = !required.contains(&name);
+                Some((name.to_string(), parameter))
+            })
+            .collect();
+        ParameterType::Object {
+            properties,
+            hide_if_empty: false,
+        }
+    }
+
+    fn import_members(&mut self, members: &Value, path: &str) -> Vec<Parameter> {
+        members
+            .as_sequence()
+            .into_iter()
+            .flatten()
+            .enumerate()
+            .map(|(i, member)| self.import(member, &format!("{path}/types/{i}")))
+            .collect()
+    }
+}
+
+fn length(schema: &Value, key: &str) -> Option<u32> {
+    schema
+        .get(key)
+        .and_then(Value::as_u64)
+        .and_then(|length| u32::try_from(length).ok())
+}
+
+/// 지원하지 않는 형식(`uuid` 등)은 제약 조건 없는 문자열로 가져옵니다.
+fn string_format(format: &str) -> Option<StringFormat> {
+    match format {
+        "email" => Some(StringFormat::Email),
+        "uri" => Some(StringFormat::Uri),
+        "date-time" => Some(StringFormat::DateTime),
+        "phone" => Some(StringFormat::Phone),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn imports_components_as_resources() {
+        let document: Value = serde_yaml_ng::from_str(
+            r##"
+openapi: 3.0.0
+components:
+  schemas:
+    Currency:
+      type: string
+      enum: [KRW, USD]
+    Amount:
+      type: object
+      description: 금액
+      required: [total]
+      properties:
+        total:
+          type: integer
+        taxFree:
+          type: integer
+          default: 0
+        currency:
+          allOf:
+            - $ref: '#/components/schemas/Currency'
+          description: 통화
+        rate:
+          type: number
+          minimum: 0
+          maximum: 1
+        receiptEmail:
+          type: string
+          format: email
+          maxLength: 320
+        metadata:
+          type: object
+          additionalProperties:
+            type: string
+    Payment:
+      oneOf:
+        - $ref: '#/components/schemas/Amount'
+        - type: object
+          properties:
+            kind:
+              const: FREE
+"##,
+        )
+        .unwrap();
+        let import = Schema::from_openapi(&document, "entity").unwrap();
+
+        let expected: Schema = serde_yaml_ng::from_str(
+            r##"
+flags: {}
+resources:
+  entity:
+    Currency:
+      type: enum
+      variants:
+        KRW: {}
+        USD: {}
+    Amount:
+      type: object
+      description: 금액
+      properties:
+        total:
+          type: integer
+        taxFree:
+          type: integer
+          default: 0
+          optional: true
+        currency:
+          type: resourceRef
+          $ref: '#/resources/entity/Currency'
+          description: 통화
+          optional: true
+        rate:
+          type: number
+          minimum: 0.0
+          maximum: 1.0
+          optional: true
+        receiptEmail:
+          type: string
+          format: email
+          maxLength: 320
+          optional: true
+        metadata:
+          type: map
+          values:
+            type: string
+          optional: true
+    Payment:
+      type: union
+      types:
+        - type: resourceRef
+          $ref: '#/resources/entity/Amount'
+        - type: object
+          properties:
+            kind:
+              type: stringLiteral
+              value: FREE
+              optional: true
+methods: {}
+"##,
+        )
+        .unwrap();
+        assert_eq!(import.schema, expected);
+        assert!(import.warnings.is_empty());
+    }
+
+    #[test]
+    fn warns_about_approximated_types() {
+        let document: Value = serde_yaml_ng::from_str(
+            r##"
+components:
+  schemas:
+    Installment:
+      type: integer
+      enum: [0, 2, 3]
+    Mixed:
+      enum: [A, 1]
+    Currency:
+      type: string
+      nullable: true
+      enum: [KRW, null]
+"##,
+        )
+        .unwrap();
+        let import = Schema::from_openapi(&document, "entity").unwrap();
+
+        let index = import.schema.build_resource_index();
+        assert_eq!(index["entity/Installment"].r#type, ParameterType::Integer);
+        assert_eq!(index["entity/Mixed"].r#type, ParameterType::Json);
+        let ParameterType::Enum { variants, .. } = &index["entity/Currency"].r#type else {
+            panic!("expected enum");
+        };
+        assert_eq!(variants.keys().collect::<Vec<_>>(), ["KRW"]);
+        assert_eq!(
+            import.warnings,
+            [
+                "#/components/schemas/Installment: enum with non-string values is imported as `integer`",
+                "#/components/schemas/Mixed: enum with non-string values is imported as `json`",
+                "#/components/schemas/Currency: `nullable` is ignored",
+                "#/components/schemas/Currency: `null` enum value is ignored",
+            ]
+        );
+    }
+}
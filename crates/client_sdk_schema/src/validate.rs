@@ -0,0 +1,1019 @@
+use std::{collections::HashSet, fmt};
+
+use indexmap::IndexMap;
+
+use crate::{
+    Flag, FlagOption, Method, Parameter, ParameterType, Resource, Schema, Version,
+    is_mobile_resource,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Severity {
+    Error,
+    Warning,
+}
+
+impl fmt::Display for Severity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Severity::Error => write!(f, "error"),
+            Severity::Warning => write!(f, "warning"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ValidationErrorKind {
+    /// 존재하지 않는 리소스를 가리키는 `$ref`
+    DanglingRef { target: String },
+    /// `resourceRef`만으로 이루어진 순환 참조
+    CyclicRef { chain: Vec<String> },
+    /// 이름을 유추할 수 없는 위치에 선언된 enum
+    UnnamedEnum,
+    /// 모바일 SDK에서 생성할 수 없는 union 멤버
+    UnsupportedUnionMember,
+    /// 객체가 아닌 intersection 멤버
+    UnsupportedIntersectionMember,
+    /// 모바일 SDK에서 표현할 수 없는 타입을 사용하여 모바일 SDK에서 제외되는 메소드
+    UnsupportedMobileMethod { method: String },
+    /// 다른 리소스와 같은 이름으로 생성되는 타입
+    DuplicateTypeName { name: String, other: String },
+    /// `flags`에 선언되지 않은 플래그를 사용하는 `flagOptions`
+    UnknownFlag { flag: String },
+    /// `minimum`이 `maximum`보다 큰 숫자 범위
+    EmptyRange,
+    /// `minLength`가 `maxLength`보다 큰 문자열 길이 범위
+    EmptyLengthRange,
+    /// `since`가 `removedIn`보다 늦거나 같은 버전 범위
+    EmptyVersionRange,
+    /// 스키마의 `version`보다 새로운 `since` 또는 `removedIn`
+    UnreleasedVersion {
+        version: Version,
+        schema_version: Version,
+    },
+    /// 파라미터 타입에 맞지 않는 `default`
+    InvalidDefault,
+    /// 기본값을 지원하지 않는 타입에 지정된 `default`
+    UnsupportedDefault,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ValidationError {
+    /// 문제가 발생한 위치 (예: `#/resources/entity/Bank/properties/code`)
+    pub path: String,
+    pub kind: ValidationErrorKind,
+}
+
+impl ValidationError {
+    pub fn severity(&self) -> Severity {
+        match &self.kind {
+            // Swift는 모든 타입을 한 모듈에 생성하므로 모바일 리소스끼리의 이름 충돌은 컴파일 오류
+            ValidationErrorKind::DuplicateTypeName { other, .. }
+                if is_mobile_resource_path(&self.path) && is_mobile_resource_path(other) =>
+            {
+                Severity::Error
+            }
+            ValidationErrorKind::DuplicateTypeName { .. }
+            | ValidationErrorKind::UnsupportedMobileMethod { .. } => Severity::Warning,
+            _ => Severity::Error,
+        }
+    }
+}
+
+impl fmt::Display for ValidationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: ", self.path)?;
+        match &self.kind {
+            ValidationErrorKind::DanglingRef { target } => {
+                write!(f, "`$ref` points to missing resource `{target}`")
+            }
+            ValidationErrorKind::CyclicRef { chain } => {
+                write!(f, "cyclic resource reference: {}", chain.join(" -> "))
+            }
+            ValidationErrorKind::UnnamedEnum => {
+                write!(f, "enum needs a `name` because none can be derived here")
+            }
+            ValidationErrorKind::UnsupportedUnionMember => write!(
+                f,
+                "union member must be a `resourceRef`, an object, a string literal or a primitive type"
+            ),
+            ValidationErrorKind::UnsupportedIntersectionMember => {
+                write!(
+                    f,
+                    "intersection member must be a `resourceRef` or an object"
+                )
+            }
+            ValidationErrorKind::UnsupportedMobileMethod { method } => write!(
+                f,
+                "method `{method}` is not generated in mobile SDKs because this type is not a primitive or a `resourceRef` to `entity`, `request`, `response` or `exception`"
+            ),
+            ValidationErrorKind::DuplicateTypeName { name, other } => {
+                write!(f, "type name `{name}` is also generated by `{other}`")
+            }
+            ValidationErrorKind::UnknownFlag { flag } => {
+                write!(f, "flag `{flag}` is not declared in `flags`")
+            }
+            ValidationErrorKind::EmptyRange => {
+                write!(f, "`minimum` is greater than `maximum`")
+            }
+            ValidationErrorKind::EmptyLengthRange => {
+                write!(f, "`minLength` is greater than `maxLength`")
+            }
+            ValidationErrorKind::EmptyVersionRange => {
+                write!(f, "`since` is not earlier than `removedIn`")
+            }
+            ValidationErrorKind::UnreleasedVersion {
+                version,
+                schema_version,
+            } => write!(
+                f,
+                "version `{version}` is newer than the schema version `{schema_version}`"
+            ),
+            ValidationErrorKind::InvalidDefault => {
+                write!(f, "`default` does not match the parameter type")
+            }
+            ValidationErrorKind::UnsupportedDefault => {
+                write!(
+                    f,
+                    "`default` is only supported on string, number, boolean and enum parameters"
+                )
+            }
+        }
+    }
+}
+
+impl Schema {
+    /// 스키마 전체를 검사하여 발견된 모든 문제를 반환합니다.
+    pub fn validate(&self) -> Vec<ValidationError> {
+        let index = self.build_resource_index();
+        let mut validator = Validator {
+            index: &index,
+            flags: &self.flags,
+            version: self.version.as_ref(),
+            errors: vec![],
+            reported_cycles: HashSet::new(),
+        };
+        validator.validate_resource(&self.resources, "");
+        validator.validate_methods(&self.methods);
+        validator.validate_type_names();
+        validator.validate_mobile_methods(self);
+        validator.errors
+    }
+}
+
+struct Validator<'a> {
+    index: &'a IndexMap<String, Parameter>,
+    flags: &'a IndexMap<String, Flag>,
+    version: Option<&'a Version>,
+    errors: Vec<ValidationError>,
+    reported_cycles: HashSet<Vec<String>>,
+}
+
+impl Validator<'_> {
+    fn report(&mut self, path: impl Into<String>, kind: ValidationErrorKind) {
+        self.errors.push(ValidationError {
+            path: path.into(),
+            kind,
+        });
+    }
+
+    fn validate_resource(&mut self, resource: &Resource, resource_path: &str) {
+        match resource {
+            Resource::SubResources(sub_resources) => {
+                for (name, sub_resource) in sub_resources {
+                    let path = if resource_path.is_empty() {
+                        name.clone()
+                    } else {
+                        format!("{resource_path}/{name}")
+                    };
+                    self.validate_resource(sub_resource, &path);
+                }
+            }
+            Resource::Parameter(parameter) => {
+                let in_mobile_root = is_mobile_resource(resource_path);
+                self.validate_parameter(
+                    parameter,
+                    &format!("#/resources/{resource_path}"),
+                    true,
+                    in_mobile_root,
+                );
+            }
+        }
+    }
+
+    fn validate_methods(&mut self, methods: &IndexMap<String, Method>) {
+        for (method_name, method) in methods {
+            let path = format!("#/methods/{method_name}");
+            self.validate_parameter(&method.input, &format!("{path}/input"), false, false);
+            if let Some(output) = &method.output {
+                self.validate_parameter(output, &format!("{path}/output"), false, false);
+            }
+            for (callback_name, callback) in method.callbacks.iter().flatten() {
+                for (parameter_name, parameter) in &callback.input {
+                    self.validate_parameter(
+                        parameter,
+                        &format!("{path}/callbacks/{callback_name}/input/{parameter_name}"),
+                        false,
+                        false,
+                    );
+                }
+            }
+        }
+    }
+
+    /// `has_name_context`는 TypeScript 코드젠이 부모 이름으로부터 타입 이름을 만들 수 있는지 여부입니다.
+    fn validate_parameter(
+        &mut self,
+        parameter: &Parameter,
+        path: &str,
+        has_name_context: bool,
+        in_mobile_root: bool,
+    ) {
+        let has_name_context = has_name_context || parameter.name.is_some();
+        self.validate_flag_options(parameter.flag_options.as_ref(), path);
+        self.validate_availability(
+            parameter.since.as_ref(),
+            parameter.removed_in.as_ref(),
+            path,
+        );
+        if let Some(default) = &parameter.default {
+            match self.default_matches(&parameter.r#type, default) {
+                Some(true) => {}
+                Some(false) => self.report(path, ValidationErrorKind::InvalidDefault),
+                None => self.report(path, ValidationErrorKind::UnsupportedDefault),
+            }
+        }
+        match &parameter.r#type {
+            ParameterType::Enum { .. } if !has_name_context => {
+                self.report(path, ValidationErrorKind::UnnamedEnum);
+            }
+            ParameterType::Enum { variants, .. } => {
+                for (value, variant) in variants {
+                    let variant_path = format!("{path}/variants/{value}");
+                    self.validate_flag_options(variant.flag_options.as_ref(), &variant_path);
+                    self.validate_availability(
+                        variant.since.as_ref(),
+                        variant.removed_in.as_ref(),
+                        &variant_path,
+                    );
+                }
+            }
+            ParameterType::Array { items, .. } => {
+                self.validate_parameter(items, &format!("{path}/items"), true, in_mobile_root);
+            }
+            ParameterType::Map { values } => {
+                self.validate_parameter(values, &format!("{path}/values"), true, in_mobile_root);
+            }
+            ParameterType::Object { properties, .. }
+            | ParameterType::OneOf { properties, .. }
+            | ParameterType::Error { properties, .. } => {
+                for (name, property) in properties {
+                    self.validate_parameter(
+                        property,
+                        &format!("{path}/properties/{name}"),
+                        true,
+                        in_mobile_root,
+                    );
+                }
+            }
+            ParameterType::Union { types, .. } | ParameterType::Intersection { types, .. } => {
+                let is_union = matches!(parameter.r#type, ParameterType::Union { .. });
+                for (i, member) in types.iter().enumerate() {
+                    let member_path = format!("{path}/types/{i}");
+                    if in_mobile_root && is_union && !is_supported_union_member(member) {
+                        self.report(&member_path, ValidationErrorKind::UnsupportedUnionMember);
+                    }
+                    if in_mobile_root && !is_union && !is_supported_intersection_member(member) {
+                        self.report(
+                            &member_path,
+                            ValidationErrorKind::UnsupportedIntersectionMember,
+                        );
+                    }
+                    self.validate_parameter(member, &member_path, true, in_mobile_root);
+                }
+            }
+            ParameterType::ResourceRef(resource_ref) => {
+                self.validate_resource_ref(resource_ref.resource_ref(), path);
+            }
+            ParameterType::Number {
+                minimum: Some(minimum),
+                maximum: Some(maximum),
+            }
+            | ParameterType::Decimal {
+                minimum: Some(minimum),
+                maximum: Some(maximum),
+            } if minimum > maximum => {
+                self.report(path, ValidationErrorKind::EmptyRange);
+            }
+            ParameterType::String {
+                min_length: Some(min_length),
+                max_length: Some(max_length),
+                ..
+            } if min_length > max_length => {
+                self.report(path, ValidationErrorKind::EmptyLengthRange);
+            }
+            _ => {}
+        }
+    }
+
+    /// 기본값이 타입에 맞는지 확인합니다. 기본값을 지원하지 않는 타입이면 `None`을 반환합니다.
+    fn default_matches(&self, r#type: &ParameterType, default: &serde_json::Value) -> Option<bool> {
+        let in_range = |value: f64, minimum: &Option<f64>, maximum: &Option<f64>| {
+            minimum.is_none_or(|minimum| value >= minimum)
+                && maximum.is_none_or(|maximum| value <= maximum)
+        };
+        let matches = match r#type {
+            ParameterType::String {
+                min_length,
+                max_length,
+                ..
+            } => default.as_str().is_some_and(|value| {
+                let length = value.chars().count();
+                min_length.is_none_or(|min_length| length >= min_length as usize)
+                    && max_length.is_none_or(|max_length| length <= max_length as usize)
+            }),
+            ParameterType::StringLiteral { value } => default.as_str() == Some(value),
+            ParameterType::Integer => default.is_i64() || default.is_u64(),
+            ParameterType::Number { minimum, maximum } => default
+                .as_f64()
+                .is_some_and(|value| in_range(value, minimum, maximum)),
+            ParameterType::Decimal { minimum, maximum } => default
+                .as_str()
+                .filter(|value| is_decimal(value))
+                .and_then(|value| value.parse::<f64>().ok())
+                .is_some_and(|value| in_range(value, minimum, maximum)),
+            ParameterType::Boolean => default.is_boolean(),
+            ParameterType::Enum { variants, .. } => default
+                .as_str()
+                .is_some_and(|value| variants.contains_key(value)),
+            // 존재하지 않거나 순환하는 참조는 `validate_resource_ref`에서 보고
+            ParameterType::ResourceRef(resource_ref) => {
+                let mut target = resource_ref.resource_ref();
+                for _ in 0..=self.index.len() {
+                    let Some(parameter) = self.index.get(target) else {
+                        break;
+                    };
+                    match &parameter.r#type {
+                        ParameterType::ResourceRef(next) => target = next.resource_ref(),
+                        r#type => return self.default_matches(r#type, default),
+                    }
+                }
+                true
+            }
+            _ => return None,
+        };
+        Some(matches)
+    }
+
+    fn validate_flag_options(
+        &mut self,
+        flag_options: Option<&IndexMap<String, FlagOption>>,
+        path: &str,
+    ) {
+        for flag in flag_options.into_iter().flat_map(IndexMap::keys) {
+            if !self.flags.contains_key(flag) {
+                self.report(
+                    path,
+                    ValidationErrorKind::UnknownFlag { flag: flag.clone() },
+                );
+            }
+        }
+    }
+
+    fn validate_availability(
+        &mut self,
+        since: Option<&Version>,
+        removed_in: Option<&Version>,
+        path: &str,
+    ) {
+        if let (Some(since), Some(removed_in)) = (since, removed_in)
+            && since >= removed_in
+        {
+            self.report(path, ValidationErrorKind::EmptyVersionRange);
+        }
+        let Some(schema_version) = self.version else {
+            return;
+        };
+        for version in since.into_iter().chain(removed_in) {
+            if version > schema_version {
+                self.report(
+                    path,
+                    ValidationErrorKind::UnreleasedVersion {
+                        version: *version,
+                        schema_version: *schema_version,
+                    },
+                );
+            }
+        }
+    }
+
+    fn validate_resource_ref(&mut self, target: &str, path: &str) {
+        let mut chain = vec![target.to_string()];
+        let mut current = target;
+        loop {
+            let Some(parameter) = self.index.get(current) else {
+                // 참조 체인 중간의 누락은 해당 리소스를 검사할 때 보고됩니다.
+                if chain.len() == 1 {
+                    self.report(
+                        path,
+                        ValidationErrorKind::DanglingRef {
+                            target: target.to_string(),
+                        },
+                    );
+                }
+                return;
+            };
+            let ParameterType::ResourceRef(next) = &parameter.r#type else {
+                return;
+            };
+            let next = next.resource_ref();
+            if let Some(start) = chain.iter().position(|p| p == next) {
+                let mut cycle = chain[start..].to_vec();
+                // 같은 순환을 여러 번 보고하지 않도록 가장 작은 경로부터 시작하는 형태로 정규화
+                let min = (0..cycle.len()).min_by_key(|&i| &cycle[i]).unwrap();
+                cycle.rotate_left(min);
+                if self.reported_cycles.insert(cycle.clone()) {
+                    cycle.push(cycle[0].clone());
+                    self.report(
+                        format!("#/resources/{}", cycle[0]),
+                        ValidationErrorKind::CyclicRef { chain: cycle },
+                    );
+                }
+                return;
+            }
+            chain.push(next.to_string());
+            current = next;
+        }
+    }
+
+    fn validate_mobile_methods(&mut self, schema: &Schema) {
+        // 모바일 코드젠과 같이 인라인 타입을 분리한 뒤 검사
+        let mut schema = schema.clone();
+        schema.hoist_inline_types();
+        let index = schema.build_resource_index();
+        for (name, method) in &schema.methods {
+            if let Some(parameter_path) = method.find_unsupported_mobile_parameter(&index) {
+                self.report(
+                    format!("#/methods/{name}/{parameter_path}"),
+                    ValidationErrorKind::UnsupportedMobileMethod {
+                        method: name.clone(),
+                    },
+                );
+            }
+        }
+    }
+
+    fn validate_type_names(&mut self) {
+        let mut seen: IndexMap<String, &str> = IndexMap::new();
+        let mut duplicates = vec![];
+        for (path, parameter) in self.index {
+            let name = parameter
+                .name
+                .clone()
+                .unwrap_or_else(|| path.rsplit('/').next().unwrap().to_string());
+            match seen.get(&name) {
+                Some(other) => duplicates.push((
+                    format!("#/resources/{path}"),
+                    ValidationErrorKind::DuplicateTypeName {
+                        name,
+                        other: format!("#/resources/{other}"),
+                    },
+                )),
+                None => {
+                    seen.insert(name, path);
+                }
+            }
+        }
+        for (path, kind) in duplicates {
+            self.report(path, kind);
+        }
+    }
+}
+
+/// `#/resources/`로 시작하는 경로가 모바일 코드젠이 생성하는 리소스를 가리키는지 확인합니다.
+fn is_mobile_resource_path(path: &str) -> bool {
+    path.strip_prefix("#/resources/")
+        .is_some_and(is_mobile_resource)
+}
+
+/// 모바일 SDK에서 union의 variant로 생성할 수 있는 멤버인지 확인합니다.
+/// 인라인 객체는 별도의 타입으로 분리되고, 문자열 리터럴과 원시 타입은 값을 감싸는 variant로 생성됩니다.
+fn is_supported_union_member(member: &Parameter) -> bool {
+    matches!(
+        member.r#type,
+        ParameterType::ResourceRef(_)
+            | ParameterType::Object { .. }
+            | ParameterType::EmptyObject
+            | ParameterType::OneOf { .. }
+            | ParameterType::Union { .. }
+            | ParameterType::Intersection { .. }
+            | ParameterType::StringLiteral { .. }
+            | ParameterType::String { .. }
+            | ParameterType::Integer
+            | ParameterType::Number { .. }
+            | ParameterType::Decimal { .. }
+            | ParameterType::Boolean
+    )
+}
+
+/// 모바일 SDK에서 intersection의 필드를 모을 수 있는 멤버인지 확인합니다.
+fn is_supported_intersection_member(member: &Parameter) -> bool {
+    matches!(
+        member.r#type,
+        ParameterType::ResourceRef(_) | ParameterType::Object { .. }
+    )
+}
+
+/// `decimal` 타입의 문자열 형식 (`-?\d+(\.\d+)?`)
+fn is_decimal(value: &str) -> bool {
+    let digits = value.strip_prefix('-').unwrap_or(value);
+    let (integer, fraction) = match digits.split_once('.') {
+        Some((integer, fraction)) => (integer, Some(fraction)),
+        None => (digits, None),
+    };
+    let is_digits = |s: &str| !s.is_empty() && s.bytes().all(|b| b.is_ascii_digit());
+    is_digits(integer) && fraction.is_none_or(is_digits)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{EnumVariant, ResourceRef};
+    use pretty_assertions::assert_eq;
+    use serde_json::json;
+
+    fn parameter(r#type: ParameterType) -> Parameter {
+        Parameter {
+            r#type,
+            ..Default::default()
+        }
+    }
+
+    fn resources(entries: Vec<(&str, Resource)>) -> Resource {
+        Resource::SubResources(
+            entries
+                .into_iter()
+                .map(|(name, resource)| (name.to_string(), resource))
+                .collect(),
+        )
+    }
+
+    fn schema(resources: Resource, methods: IndexMap<String, Method>) -> Schema {
+        Schema {
+            flags: IndexMap::new(),
+            resources,
+            methods,
+            version: None,
+        }
+    }
+
+    #[test]
+    fn valid_schema_has_no_errors() {
+        let schema = schema(
+            resources(vec![(
+                "entity",
+                resources(vec![
+                    (
+                        "Country",
+                        Resource::Parameter(parameter(ParameterType::string())),
+                    ),
+                    (
+                        "Address",
+                        Resource::Parameter(parameter(ParameterType::Object {
+                            properties: IndexMap::from([(
+                                "country".to_string(),
+                                parameter(ParameterType::ResourceRef(ResourceRef::new(
+                                    "#/resources/entity/Country",
+                                ))),
+                            )]),
+                            hide_if_empty: false,
+                        })),
+                    ),
+                ]),
+            )]),
+            IndexMap::new(),
+        );
+        assert_eq!(schema.validate(), vec![]);
+    }
+
+    #[test]
+    fn reports_dangling_and_cyclic_refs() {
+        let schema = schema(
+            resources(vec![(
+                "entity",
+                resources(vec![
+                    (
+                        "A",
+                        Resource::Parameter(parameter(ParameterType::ResourceRef(
+                            ResourceRef::new("#/resources/entity/B"),
+                        ))),
+                    ),
+                    (
+                        "B",
+                        Resource::Parameter(parameter(ParameterType::ResourceRef(
+                            ResourceRef::new("#/resources/entity/A"),
+                        ))),
+                    ),
+                    (
+                        "C",
+                        Resource::Parameter(parameter(ParameterType::Array {
+                            items: Box::new(parameter(ParameterType::ResourceRef(
+                                ResourceRef::new("#/resources/entity/Missing"),
+                            ))),
+                            hide_if_empty: false,
+                        })),
+                    ),
+                ]),
+            )]),
+            IndexMap::new(),
+        );
+        assert_eq!(
+            schema.validate(),
+            vec![
+                ValidationError {
+                    path: "#/resources/entity/A".to_string(),
+                    kind: ValidationErrorKind::CyclicRef {
+                        chain: vec![
+                            "entity/A".to_string(),
+                            "entity/B".to_string(),
+                            "entity/A".to_string(),
+                        ],
+                    },
+                },
+                ValidationError {
+                    path: "#/resources/entity/C/items".to_string(),
+                    kind: ValidationErrorKind::DanglingRef {
+                        target: "entity/Missing".to_string(),
+                    },
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn reports_unnamed_enum_and_unsupported_union_member() {
+        let union = parameter(ParameterType::Union {
+            types: vec![
+                parameter(ParameterType::StringLiteral {
+                    value: "A".to_string(),
+                }),
+                parameter(ParameterType::Json),
+            ],
+            hide_if_empty: false,
+        });
+        let method = Method {
+            description: None,
+            input: parameter(ParameterType::Enum {
+                variants: IndexMap::from([(
+                    "A".to_string(),
+                    EnumVariant {
+                        description: None,
+                        alias: None,
+                        flag_options: None,
+                        deprecated: false,
+                        replacement: None,
+                        since: None,
+                        removed_in: None,
+                    },
+                )]),
+                value_prefix: None,
+            }),
+            callbacks: None,
+            output: None,
+            deprecated: false,
+            replacement: None,
+        };
+        let schema = schema(
+            resources(vec![
+                (
+                    "entity",
+                    resources(vec![("U", Resource::Parameter(union.clone()))]),
+                ),
+                ("misc", resources(vec![("U2", Resource::Parameter(union))])),
+            ]),
+            IndexMap::from([("request".to_string(), method)]),
+        );
+        let errors = schema.validate();
+        assert_eq!(
+            errors,
+            vec![
+                ValidationError {
+                    path: "#/resources/entity/U/types/1".to_string(),
+                    kind: ValidationErrorKind::UnsupportedUnionMember,
+                },
+                ValidationError {
+                    path: "#/methods/request/input".to_string(),
+                    kind: ValidationErrorKind::UnnamedEnum,
+                },
+            ]
+        );
+        assert!(errors.iter().all(|e| e.severity() == Severity::Error));
+    }
+
+    #[test]
+    fn reports_duplicate_type_names() {
+        let schema = schema(
+            resources(vec![
+                (
+                    "entity",
+                    resources(vec![(
+                        "Bank",
+                        Resource::Parameter(parameter(ParameterType::string())),
+                    )]),
+                ),
+                (
+                    "misc",
+                    resources(vec![(
+                        "Bank",
+                        Resource::Parameter(parameter(ParameterType::string())),
+                    )]),
+                ),
+                (
+                    "request",
+                    resources(vec![(
+                        "BankCode",
+                        Resource::Parameter(Parameter {
+                            name: Some("Bank".to_string()),
+                            ..parameter(ParameterType::string())
+                        }),
+                    )]),
+                ),
+            ]),
+            IndexMap::new(),
+        );
+        let errors = schema.validate();
+        assert_eq!(
+            errors,
+            vec![
+                ValidationError {
+                    path: "#/resources/misc/Bank".to_string(),
+                    kind: ValidationErrorKind::DuplicateTypeName {
+                        name: "Bank".to_string(),
+                        other: "#/resources/entity/Bank".to_string(),
+                    },
+                },
+                ValidationError {
+                    path: "#/resources/request/BankCode".to_string(),
+                    kind: ValidationErrorKind::DuplicateTypeName {
+                        name: "Bank".to_string(),
+                        other: "#/resources/entity/Bank".to_string(),
+                    },
+                },
+            ]
+        );
+        // 모바일 SDK가 생성하지 않는 리소스와의 충돌은 경고
+        assert_eq!(errors[0].severity(), Severity::Warning);
+        assert_eq!(errors[1].severity(), Severity::Error);
+        assert_eq!(
+            errors[1].to_string(),
+            "#/resources/request/BankCode: type name `Bank` is also generated by `#/resources/entity/Bank`"
+        );
+    }
+
+    #[test]
+    fn reports_methods_excluded_from_mobile_sdks() {
+        let method = |input: Parameter| Method {
+            description: None,
+            input,
+            callbacks: None,
+            output: None,
+            deprecated: false,
+            replacement: None,
+        };
+        let object = parameter(ParameterType::Object {
+            properties: IndexMap::new(),
+            hide_if_empty: false,
+        });
+        let schema = schema(
+            resources(vec![(
+                "request",
+                resources(vec![(
+                    "PaymentRequest",
+                    Resource::Parameter(object.clone()),
+                )]),
+            )]),
+            IndexMap::from([
+                (
+                    "requestPayment".to_string(),
+                    method(parameter(ParameterType::ResourceRef(ResourceRef::new(
+                        "#/resources/request/PaymentRequest",
+                    )))),
+                ),
+                ("loadPaymentUI".to_string(), method(object)),
+            ]),
+        );
+        let errors = schema.validate();
+        assert_eq!(
+            errors,
+            vec![ValidationError {
+                path: "#/methods/loadPaymentUI/input".to_string(),
+                kind: ValidationErrorKind::UnsupportedMobileMethod {
+                    method: "loadPaymentUI".to_string(),
+                },
+            }]
+        );
+        assert_eq!(errors[0].severity(), Severity::Warning);
+    }
+
+    #[test]
+    fn reports_unknown_flags() {
+        let flag_options = Some(IndexMap::from([("kcp".to_string(), FlagOption::new(true))]));
+        let mut schema = schema(
+            resources(vec![(
+                "Request",
+                Resource::Parameter(parameter(ParameterType::Object {
+                    properties: IndexMap::from([(
+                        "bypass".to_string(),
+                        Parameter {
+                            flag_options: flag_options.clone(),
+                            ..parameter(ParameterType::Json)
+                        },
+                    )]),
+                    hide_if_empty: false,
+                })),
+            )]),
+            IndexMap::new(),
+        );
+        assert_eq!(
+            schema.validate(),
+            vec![ValidationError {
+                path: "#/resources/Request/properties/bypass".to_string(),
+                kind: ValidationErrorKind::UnknownFlag {
+                    flag: "kcp".to_string()
+                },
+            }]
+        );
+
+        schema.flags.insert(
+            "kcp".to_string(),
+            Flag {
+                description: "KCP".to_string(),
+            },
+        );
+        assert_eq!(schema.validate(), vec![]);
+    }
+
+    #[test]
+    fn reports_empty_range() {
+        let schema = schema(
+            resources(vec![
+                (
+                    "Rate",
+                    Resource::Parameter(parameter(ParameterType::Number {
+                        minimum: Some(1.0),
+                        maximum: Some(0.0),
+                    })),
+                ),
+                (
+                    "Amount",
+                    Resource::Parameter(parameter(ParameterType::Decimal {
+                        minimum: Some(0.0),
+                        maximum: None,
+                    })),
+                ),
+                (
+                    "Code",
+                    Resource::Parameter(parameter(ParameterType::String {
+                        pattern: None,
+                        min_length: Some(10),
+                        max_length: Some(4),
+                        format: None,
+                    })),
+                ),
+            ]),
+            IndexMap::new(),
+        );
+        assert_eq!(
+            schema.validate(),
+            vec![
+                ValidationError {
+                    path: "#/resources/Rate".to_string(),
+                    kind: ValidationErrorKind::EmptyRange,
+                },
+                ValidationError {
+                    path: "#/resources/Code".to_string(),
+                    kind: ValidationErrorKind::EmptyLengthRange,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn reports_invalid_availability() {
+        let available = |since: Option<Version>, removed_in: Option<Version>| {
+            Resource::Parameter(Parameter {
+                since,
+                removed_in,
+                ..parameter(ParameterType::string())
+            })
+        };
+        let mut schema = schema(
+            resources(vec![
+                (
+                    "Current",
+                    available(Some(Version::new(1, 0, 0)), Some(Version::new(2, 0, 0))),
+                ),
+                (
+                    "Reversed",
+                    available(Some(Version::new(2, 0, 0)), Some(Version::new(1, 0, 0))),
+                ),
+                ("Unreleased", available(Some(Version::new(3, 0, 0)), None)),
+            ]),
+            IndexMap::new(),
+        );
+        schema.version = Some(Version::new(2, 1, 0));
+        assert_eq!(
+            schema.validate(),
+            vec![
+                ValidationError {
+                    path: "#/resources/Reversed".to_string(),
+                    kind: ValidationErrorKind::EmptyVersionRange,
+                },
+                ValidationError {
+                    path: "#/resources/Unreleased".to_string(),
+                    kind: ValidationErrorKind::UnreleasedVersion {
+                        version: Version::new(3, 0, 0),
+                        schema_version: Version::new(2, 1, 0),
+                    },
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn reports_invalid_default() {
+        let with_default = |r#type: ParameterType, default: serde_json::Value| {
+            Resource::Parameter(Parameter {
+                default: Some(Box::new(default)),
+                ..parameter(r#type)
+            })
+        };
+        let locale = ParameterType::Enum {
+            variants: IndexMap::from([(
+                "KO_KR".to_string(),
+                EnumVariant {
+                    description: None,
+                    alias: None,
+                    flag_options: None,
+                    deprecated: false,
+                    replacement: None,
+                    since: None,
+                    removed_in: None,
+                },
+            )]),
+            value_prefix: None,
+        };
+        let schema = schema(
+            resources(vec![
+                ("Locale", Resource::Parameter(parameter(locale))),
+                (
+                    "DefaultLocale",
+                    with_default(
+                        ParameterType::ResourceRef(ResourceRef::new("#/resources/Locale")),
+                        json!("KO_KR"),
+                    ),
+                ),
+                (
+                    "UnknownLocale",
+                    with_default(
+                        ParameterType::ResourceRef(ResourceRef::new("#/resources/Locale")),
+                        json!("EN_US"),
+                    ),
+                ),
+                (
+                    "IsTest",
+                    with_default(ParameterType::Boolean, json!("true")),
+                ),
+                (
+                    "Rate",
+                    with_default(
+                        ParameterType::Number {
+                            minimum: Some(0.0),
+                            maximum: Some(1.0),
+                        },
+                        json!(0.5),
+                    ),
+                ),
+                ("Tags", with_default(ParameterType::Json, json!({}))),
+            ]),
+            IndexMap::new(),
+        );
+        assert_eq!(
+            schema.validate(),
+            vec![
+                ValidationError {
+                    path: "#/resources/UnknownLocale".to_string(),
+                    kind: ValidationErrorKind::InvalidDefault,
+                },
+                ValidationError {
+                    path: "#/resources/IsTest".to_string(),
+                    kind: ValidationErrorKind::InvalidDefault,
+                },
+                ValidationError {
+                    path: "#/resources/Tags".to_string(),
+                    kind: ValidationErrorKind::UnsupportedDefault,
+                },
+            ]
+        );
+    }
+}
@@ -0,0 +1,283 @@
+use std::{fmt, str::FromStr};
+
+use indexmap::IndexMap;
+use schemars::{JsonSchema, Schema as JsonSchemaObject, SchemaGenerator, json_schema};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::{EnumVariant, Parameter, ParameterType, Resource, Schema};
+
+/// `major.minor.patch` 형식의 스키마 버전
+///
+/// `patch`는 생략할 수 있으며, 생략하면 0으로 취급합니다.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Version {
+    pub major: u32,
+    pub minor: u32,
+    pub patch: u32,
+}
+
+impl Version {
+    pub const fn new(major: u32, minor: u32, patch: u32) -> Self {
+        Self {
+            major,
+            minor,
+            patch,
+        }
+    }
+}
+
+impl FromStr for Version {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let parts = s
+            .trim()
+            .trim_start_matches('v')
+            .split('.')
+            .map(str::parse::<u32>)
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|_| format!("invalid version `{s}`"))?;
+        match parts[..] {
+            [major, minor] => Ok(Self::new(major, minor, 0)),
+            [major, minor, patch] => Ok(Self::new(major, minor, patch)),
+            _ => Err(format!("invalid version `{s}`")),
+        }
+    }
+}
+
+impl fmt::Display for Version {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}.{}.{}", self.major, self.minor, self.patch)
+    }
+}
+
+impl Serialize for Version {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Version {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
+impl JsonSchema for Version {
+    fn schema_name() -> std::borrow::Cow<'static, str> {
+        "Version".into()
+    }
+
+    fn json_schema(_: &mut SchemaGenerator) -> JsonSchemaObject {
+        json_schema!({
+            "type": "string",
+            "pattern": r"^v?\d+\.\d+(\.\d+)?$",
+        })
+    }
+}
+
+/// `since`와 `removedIn`으로 표시된 항목이 주어진 버전에 존재하는지 확인합니다.
+pub(crate) fn is_available(
+    since: Option<&Version>,
+    removed_in: Option<&Version>,
+    version: &Version,
+) -> bool {
+    since.is_none_or(|since| since <= version)
+        && removed_in.is_none_or(|removed_in| version < removed_in)
+}
+
+impl Schema {
+    /// `since`와 `removedIn`이 지정된 파라미터와 enum variant의 설명에 사용 가능한 버전을 덧붙입니다.
+    ///
+    /// 설명이 없는 파라미터는 참조하는 리소스나 배열 항목의 설명을 물려받은 뒤 덧붙입니다.
+    pub fn document_availability(&mut self) {
+        let index = self.build_resource_index();
+        let document = |parameter: &mut Parameter| document_parameter(parameter, &index);
+        document_resource(&mut self.resources, &index);
+        for method in self.methods.values_mut() {
+            document(&mut method.input);
+            if let Some(output) = &mut method.output {
+                document(output);
+            }
+            for callback in method.callbacks.iter_mut().flat_map(|c| c.values_mut()) {
+                callback.input.values_mut().for_each(document);
+            }
+        }
+    }
+}
+
+fn availability_note(since: Option<&Version>, removed_in: Option<&Version>) -> Option<String> {
+    match (since, removed_in) {
+        (None, None) => None,
+        (Some(since), None) => Some(format!("`{since}` 버전부터 사용할 수 있습니다.")),
+        (None, Some(removed_in)) => Some(format!("`{removed_in}` 버전에서 제거됩니다.")),
+        (Some(since), Some(removed_in)) => Some(format!(
+            "`{since}` 버전부터 사용할 수 있으며, `{removed_in}` 버전에서 제거됩니다."
+        )),
+    }
+}
+
+fn append_note(description: &mut Option<String>, note: Option<String>) {
+    if let Some(note) = note {
+        *description = Some(match description.take() {
+            Some(description) => format!("{description}\n\n{note}"),
+            None => note,
+        });
+    }
+}
+
+/// `ParameterExt::description`과 같이 설명이 없으면 참조하는 리소스나 배열 항목의 설명을 반환합니다.
+fn inherited_description(
+    parameter: &Parameter,
+    index: &IndexMap<String, Parameter>,
+) -> Option<String> {
+    match (&parameter.description, &parameter.r#type) {
+        (None, ParameterType::ResourceRef(resource_ref)) => index
+            .get(resource_ref.resource_ref())
+            .and_then(|parameter| inherited_description(parameter, index)),
+        (None, ParameterType::Array { items, .. }) => inherited_description(items, index),
+        (description, _) => description.clone(),
+    }
+}
+
+fn document_resource(resource: &mut Resource, index: &IndexMap<String, Parameter>) {
+    match resource {
+        Resource::SubResources(sub_resources) => {
+            for sub_resource in sub_resources.values_mut() {
+                document_resource(sub_resource, index);
+            }
+        }
+        Resource::Parameter(parameter) => document_parameter(parameter, index),
+    }
+}
+
+fn document_parameter(parameter: &mut Parameter, index: &IndexMap<String, Parameter>) {
+    let note = availability_note(parameter.since.as_ref(), parameter.removed_in.as_ref());
+    if note.is_some() && parameter.description.is_none() {
+        // 설명을 직접 지정하면 코드젠이 더 이상 설명을 물려받지 않으므로 미리 가져옴
+        parameter.description = inherited_description(parameter, index);
+    }
+    append_note(&mut parameter.description, note);
+    match &mut parameter.r#type {
+        ParameterType::Array { items, .. } | ParameterType::Map { values: items } => {
+            document_parameter(items, index)
+        }
+        ParameterType::Object { properties, .. }
+        | ParameterType::OneOf { properties, .. }
+        | ParameterType::Error { properties, .. } => {
+            for property in properties.values_mut() {
+                document_parameter(property, index);
+            }
+        }
+        ParameterType::Enum { variants, .. } => {
+            variants.values_mut().for_each(document_variant);
+        }
+        ParameterType::Union { types, .. } | ParameterType::Intersection { types, .. } => {
+            for member in types.iter_mut() {
+                document_parameter(member, index);
+            }
+        }
+        _ => {}
+    }
+}
+
+fn document_variant(variant: &mut EnumVariant) {
+    append_note(
+        &mut variant.description,
+        availability_note(variant.since.as_ref(), variant.removed_in.as_ref()),
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ResourceRef;
+
+    #[test]
+    fn parses_and_orders_versions() {
+        let version: Version = "2.1".parse().unwrap();
+        assert_eq!(version, Version::new(2, 1, 0));
+        assert_eq!(version.to_string(), "2.1.0");
+        assert!("v2.1.3".parse::<Version>().unwrap() > version);
+        assert!("2".parse::<Version>().is_err());
+        assert!("2.x".parse::<Version>().is_err());
+    }
+
+    #[test]
+    fn documents_availability_with_inherited_description() {
+        let property = |r#type: ParameterType| Parameter {
+            since: Some(Version::new(2, 0, 0)),
+            r#type,
+            ..Default::default()
+        };
+        let bank_ref = || ParameterType::ResourceRef(ResourceRef::new("#/resources/Bank"));
+        let mut schema = Schema {
+            version: None,
+            flags: IndexMap::new(),
+            resources: Resource::SubResources(IndexMap::from([
+                (
+                    "Bank".to_string(),
+                    Resource::Parameter(Parameter {
+                        description: Some("은행".to_string()),
+                        r#type: ParameterType::string(),
+                        ..Default::default()
+                    }),
+                ),
+                (
+                    "Account".to_string(),
+                    Resource::Parameter(Parameter {
+                        r#type: ParameterType::Object {
+                            properties: IndexMap::from([
+                                ("bank".to_string(), property(bank_ref())),
+                                (
+                                    "banks".to_string(),
+                                    property(ParameterType::Array {
+                                        items: Box::new(Parameter {
+                                            r#type: bank_ref(),
+                                            ..Default::default()
+                                        }),
+                                        hide_if_empty: false,
+                                    }),
+                                ),
+                                ("number".to_string(), property(ParameterType::string())),
+                            ]),
+                            hide_if_empty: false,
+                        },
+                        ..Default::default()
+                    }),
+                ),
+            ])),
+            methods: IndexMap::new(),
+        };
+        schema.document_availability();
+
+        let index = schema.build_resource_index();
+        let ParameterType::Object { properties, .. } = &index["Account"].r#type else {
+            panic!("expected object");
+        };
+        let description = |name: &str| properties[name].description.as_deref();
+        let note = "`2.0.0` 버전부터 사용할 수 있습니다.";
+        assert_eq!(
+            description("bank"),
+            Some(format!("은행\n\n{note}").as_str())
+        );
+        assert_eq!(
+            description("banks"),
+            Some(format!("은행\n\n{note}").as_str())
+        );
+        assert_eq!(description("number"), Some(note));
+        assert_eq!(index["Bank"].description.as_deref(), Some("은행"));
+    }
+
+    #[test]
+    fn checks_availability() {
+        let since = Version::new(2, 0, 0);
+        let removed_in = Version::new(3, 0, 0);
+        let available = |version| is_available(Some(&since), Some(&removed_in), &version);
+        assert!(!available(Version::new(1, 9, 0)));
+        assert!(available(Version::new(2, 0, 0)));
+        assert!(!available(Version::new(3, 0, 0)));
+    }
+}
@@ -1,6 +1,6 @@
 use std::fmt;
 
-use super::{Comment, Identifier, Indent, UnionParent};
+use super::{Comment, DeprecatedAttribute, Identifier, Indent, UnionParent};
 
 #[derive(Debug, Clone)]
 pub struct Enum {
@@ -8,6 +8,7 @@ pub struct Enum {
     pub description: Option<Comment>,
     pub variants: Vec<EnumVariant>,
     pub union_parents: Vec<UnionParent>,
+    pub deprecated: bool,
 }
 
 #[derive(Debug, Clone)]
@@ -15,6 +16,8 @@ pub struct EnumVariant {
     pub name: Identifier,
     pub value: String,
     pub description: Option<Comment>,
+    pub deprecated: bool,
+    pub replacement: Option<String>,
 }
 
 impl fmt::Display for Enum {
@@ -22,6 +25,9 @@ impl fmt::Display for Enum {
         if let Some(ref desc) = self.description {
             writeln!(f, "/// {}", desc.lines().collect::<Vec<_>>().join("\n/// "))?;
         }
+        if self.deprecated {
+            writeln!(f, "{}", DeprecatedAttribute(None))?;
+        }
         writeln!(
             f,
             "public enum {name}: String, Codable {{",
@@ -35,6 +41,13 @@ impl fmt::Display for Enum {
                         writeln!(f, "{indent}/// {line}")?;
                     }
                 }
+                if variant.deprecated {
+                    writeln!(
+                        f,
+                        "{indent}{}",
+                        DeprecatedAttribute(variant.replacement.as_deref())
+                    )?;
+                }
                 writeln!(f, "{indent}{variant}")?;
             }
         }
@@ -70,19 +83,26 @@ mod tests {
                     name: Identifier::try_from("BANK_OF_KOREA").unwrap(),
                     value: "BANK_OF_KOREA".into(),
                     description: Some(Comment::try_from("한국은행").unwrap()),
+                    deprecated: false,
+                    replacement: None,
                 },
                 EnumVariant {
                     name: Identifier::try_from("KOREA_DEVELOPMENT_BANK").unwrap(),
                     value: "KOREA_DEVELOPMENT_BANK".into(),
                     description: Some(Comment::try_from("산업은행").unwrap()),
+                    deprecated: false,
+                    replacement: None,
                 },
                 EnumVariant {
                     name: Identifier::try_from("CAPE_INVESTMENT_CERTIFICATE").unwrap(),
                     value: "CAPE_INVESTMENT_CERTIFICATE".into(),
                     description: Some(Comment::try_from("케이프투자증권").unwrap()),
+                    deprecated: false,
+                    replacement: None,
                 },
             ],
             union_parents: vec![],
+            deprecated: false,
         };
 
         let expected = r#"/// 계좌이체, 가상계좌 발급시 사용되는 은행 코드
@@ -109,19 +129,26 @@ public enum Bank: String, Codable {
                     name: Identifier::try_from("2checkout").unwrap(),
                     value: "2checkout".into(),
                     description: Some(Comment::try_from("2Checkout 결제").unwrap()),
+                    deprecated: false,
+                    replacement: None,
                 },
                 EnumVariant {
                     name: Identifier::try_from("3ds").unwrap(),
                     value: "3ds".into(),
                     description: Some(Comment::try_from("3D Secure 인증").unwrap()),
+                    deprecated: false,
+                    replacement: None,
                 },
                 EnumVariant {
                     name: Identifier::try_from("card").unwrap(),
                     value: "card".into(),
                     description: Some(Comment::try_from("카드 결제").unwrap()),
+                    deprecated: false,
+                    replacement: None,
                 },
             ],
             union_parents: vec![],
+            deprecated: false,
         };
 
         let expected = r#"/// 결제 수단
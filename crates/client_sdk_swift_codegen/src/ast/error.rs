@@ -0,0 +1,157 @@
+use std::fmt;
+
+use super::{Comment, Identifier, Indent, ObjectField};
+
+/// 모든 에러 타입이 따르는 기본 protocol 이름
+pub const BASE_ERROR_NAME: &str = "PortOneError";
+
+#[derive(Debug, Clone)]
+pub struct ErrorClass {
+    pub name: Identifier,
+    pub description: Option<Comment>,
+    pub transaction_type: Option<String>,
+    pub fields: Vec<ObjectField>,
+}
+
+impl fmt::Display for ErrorClass {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(ref desc) = self.description {
+            for line in desc.lines() {
+                writeln!(f, "/// {line}")?;
+            }
+        }
+        writeln!(
+            f,
+            "public struct {name}: {BASE_ERROR_NAME}, Codable {{",
+            name = self.name.as_ref()
+        )?;
+        {
+            let indent = Indent(1);
+            for field in self.fields.iter() {
+                if let Some(ref desc) = field.description {
+                    for line in desc.lines() {
+                        writeln!(f, "{indent}/// {line}")?;
+                    }
+                }
+                writeln!(f, "{indent}{field}")?;
+            }
+            if let Some(transaction_type) = &self.transaction_type {
+                if !self.fields.is_empty() {
+                    writeln!(f)?;
+                }
+                writeln!(
+                    f,
+                    "{indent}public var transactionType: String? {{ \"{transaction_type}\" }}"
+                )?;
+            }
+
+            if self
+                .fields
+                .iter()
+                .any(|field| field.name.as_ref() != field.serialized_name)
+            {
+                writeln!(f)?;
+                writeln!(f, "{indent}private enum CodingKeys: String, CodingKey {{")?;
+                {
+                    let indent = Indent(2);
+                    for field in self.fields.iter() {
+                        let name = field.name.as_ref();
+                        let serialized = &field.serialized_name;
+                        if name == serialized {
+                            writeln!(f, "{indent}case {name}")?;
+                        } else {
+                            writeln!(f, "{indent}case {name} = \"{serialized}\"")?;
+                        }
+                    }
+                }
+                writeln!(f, "{indent}}}")?;
+            }
+
+            writeln!(f)?;
+            let parameters = self
+                .fields
+                .iter()
+                .map(|field| {
+                    let default_value = field.default_argument();
+                    format!(
+                        "{name}: {value_type}{default_value}",
+                        name = field.name.as_ref(),
+                        value_type = field.value_type
+                    )
+                })
+                .collect::<Vec<_>>()
+                .join(", ");
+            writeln!(f, "{indent}public init({parameters}) {{")?;
+            {
+                let indent = Indent(2);
+                for field in self.fields.iter() {
+                    let field_name = field.name.as_ref();
+                    writeln!(f, "{indent}self.{field_name} = {field_name}")?;
+                }
+            }
+            writeln!(f, "{indent}}}")?;
+        }
+        writeln!(f, "}}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ast::{CompositeType, ScalarType};
+
+    use super::*;
+
+    #[test]
+    fn error_struct() {
+        let error = ErrorClass {
+            name: Identifier::try_from("PaymentError").unwrap(),
+            description: Some(Comment::try_from("결제 실패 시 발생하는 에러").unwrap()),
+            transaction_type: Some("PAYMENT".to_string()),
+            fields: vec![
+                ObjectField {
+                    name: Identifier::try_from("paymentId").unwrap(),
+                    serialized_name: "paymentId".to_string(),
+                    value_type: CompositeType {
+                        scalar: ScalarType::String,
+                        is_list: false,
+                        is_required: false,
+                    },
+                    description: None,
+                    hide_if_empty: false,
+                    deprecated: false,
+                    default_value: None,
+                },
+                ObjectField {
+                    name: Identifier::try_from("message").unwrap(),
+                    serialized_name: "message".to_string(),
+                    value_type: CompositeType {
+                        scalar: ScalarType::String,
+                        is_list: false,
+                        is_required: true,
+                    },
+                    description: Some(Comment::try_from("에러 메시지").unwrap()),
+                    hide_if_empty: false,
+                    deprecated: false,
+                    default_value: None,
+                },
+            ],
+        };
+        assert_eq!(
+            error.to_string(),
+            r#"/// 결제 실패 시 발생하는 에러
+public struct PaymentError: PortOneError, Codable {
+    public let paymentId: String?
+    /// 에러 메시지
+    public let message: String
+
+    public var transactionType: String? { "PAYMENT" }
+
+    public init(paymentId: String? = nil, message: String) {
+        self.paymentId = paymentId
+        self.message = message
+    }
+}
+"#
+        );
+    }
+}
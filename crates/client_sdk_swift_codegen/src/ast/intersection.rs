@@ -1,6 +1,9 @@
 use std::fmt;
 
-use super::{Comment, Identifier, Indent, ObjectField, TypeReference, UnionParent};
+use super::{
+    Comment, DeprecatedAttribute, Identifier, Indent, ObjectField, TypeReference, UnionParent,
+    object::EncodeFields,
+};
 
 #[derive(Debug, Clone)]
 pub struct Intersection {
@@ -9,6 +12,7 @@ pub struct Intersection {
     pub constituents: Vec<IntersectionConstituent>,
     pub fields: Vec<ObjectField>, // Flattened fields from all constituents
     pub union_parents: Vec<UnionParent>,
+    pub deprecated: bool,
 }
 
 #[derive(Debug, Clone)]
@@ -25,6 +29,9 @@ impl fmt::Display for Intersection {
                 writeln!(f, "/// {line}")?;
             }
         }
+        if self.deprecated {
+            writeln!(f, "{}", DeprecatedAttribute(None))?;
+        }
 
         // Struct declaration with flattened fields
         writeln!(
@@ -78,15 +85,11 @@ impl fmt::Display for Intersection {
                 } else {
                     "?"
                 };
-                let default_value = if field.value_type.is_required {
-                    ""
-                } else {
-                    " = nil"
-                };
+                let default_value = field.default_argument();
                 let field_type = if field.value_type.is_list {
                     format!("[{}]", field.value_type.scalar.to_swift_type())
                 } else {
-                    field.value_type.scalar.to_swift_type().to_string()
+                    field.value_type.scalar.to_swift_type()
                 };
                 write!(
                     f,
@@ -103,6 +106,11 @@ impl fmt::Display for Intersection {
                 }
             }
             writeln!(f, "{indent}}}")?;
+
+            if self.fields.iter().any(|field| field.hide_if_empty) {
+                writeln!(f)?;
+                write!(f, "{}", EncodeFields(&self.fields))?;
+            }
         }
         writeln!(f, "}}")
     }
@@ -146,6 +154,9 @@ mod tests {
                         is_required: true,
                     },
                     description: Some(Comment::try_from("결제 금액").unwrap()),
+                    hide_if_empty: false,
+                    deprecated: false,
+                    default_value: None,
                 },
                 ObjectField {
                     name: Identifier::try_from("currency").unwrap(),
@@ -156,6 +167,9 @@ mod tests {
                         is_required: true,
                     },
                     description: Some(Comment::try_from("통화 코드").unwrap()),
+                    hide_if_empty: false,
+                    deprecated: false,
+                    default_value: None,
                 },
                 // Fields from PaymentRequestUnion
                 ObjectField {
@@ -170,6 +184,9 @@ mod tests {
                         is_required: true,
                     },
                     description: Some(Comment::try_from("결제 수단").unwrap()),
+                    hide_if_empty: false,
+                    deprecated: false,
+                    default_value: None,
                 },
                 ObjectField {
                     name: Identifier::try_from("cardInfo").unwrap(),
@@ -183,9 +200,13 @@ mod tests {
                         is_required: false,
                     },
                     description: Some(Comment::try_from("카드 정보").unwrap()),
+                    hide_if_empty: false,
+                    deprecated: false,
+                    default_value: None,
                 },
             ],
             union_parents: vec![],
+            deprecated: false,
         };
 
         assert_eq!(
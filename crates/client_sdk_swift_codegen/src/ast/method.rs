@@ -0,0 +1,234 @@
+use std::fmt;
+
+use super::{Comment, CompositeType, DeprecatedAttribute, Identifier, Indent};
+
+/// 스키마의 메소드 목록을 모은 SDK 진입점 protocol
+#[derive(Debug, Clone)]
+pub struct MethodFacade {
+    pub name: Identifier,
+    pub methods: Vec<Method>,
+}
+
+#[derive(Debug, Clone)]
+pub struct Method {
+    pub name: Identifier,
+    pub description: Option<Comment>,
+    pub input: CompositeType,
+    pub output: Option<CompositeType>,
+    pub callbacks: Vec<Callback>,
+    pub deprecated: bool,
+    pub replacement: Option<String>,
+}
+
+/// 메소드에 closure 파라미터로 전달되는 콜백
+#[derive(Debug, Clone)]
+pub struct Callback {
+    pub name: Identifier,
+    pub description: Option<Comment>,
+    pub parameters: Vec<CompositeType>,
+    pub deprecated: bool,
+    pub replacement: Option<String>,
+}
+
+impl fmt::Display for MethodFacade {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "public protocol {name} {{", name = self.name.as_ref())?;
+        let indent = Indent(1);
+        for method in self.methods.iter() {
+            if let Some(ref desc) = method.description {
+                for line in desc.lines() {
+                    writeln!(f, "{indent}/// {line}")?;
+                }
+            }
+            // closure 파라미터에는 속성을 붙일 수 없으므로 문서에 deprecation을 표시
+            let documented_callbacks = method
+                .callbacks
+                .iter()
+                .filter_map(|callback| {
+                    let desc = callback
+                        .description
+                        .as_ref()
+                        .map(|desc| desc.lines().collect::<Vec<_>>().join(" "));
+                    let notice = callback.deprecated.then(|| match &callback.replacement {
+                        Some(replacement) => format!("Deprecated. Use `{replacement}` instead."),
+                        None => "Deprecated.".to_string(),
+                    });
+                    let desc = match (desc, notice) {
+                        (Some(desc), Some(notice)) => format!("{desc} {notice}"),
+                        (desc, notice) => desc.or(notice)?,
+                    };
+                    Some((&callback.name, desc))
+                })
+                .collect::<Vec<_>>();
+            if !documented_callbacks.is_empty() {
+                if method.description.is_some() {
+                    writeln!(f, "{indent}///")?;
+                }
+                writeln!(f, "{indent}/// - Parameters:")?;
+                for (name, desc) in documented_callbacks {
+                    writeln!(f, "{indent}///   - {name}: {desc}")?;
+                }
+            }
+
+            if method.deprecated {
+                writeln!(
+                    f,
+                    "{indent}{}",
+                    DeprecatedAttribute(method.replacement.as_deref())
+                )?;
+            }
+
+            let output = match &method.output {
+                Some(output) => format!(" -> {output}"),
+                None => String::new(),
+            };
+            if method.callbacks.is_empty() {
+                writeln!(
+                    f,
+                    "{indent}func {name}(request: {input}) async throws{output}",
+                    name = method.name.as_ref(),
+                    input = method.input
+                )?;
+            } else {
+                writeln!(f, "{indent}func {name}(", name = method.name.as_ref())?;
+                {
+                    let indent = Indent(2);
+                    write!(f, "{indent}request: {input}", input = method.input)?;
+                    for callback in method.callbacks.iter() {
+                        let parameters = callback
+                            .parameters
+                            .iter()
+                            .map(ToString::to_string)
+                            .collect::<Vec<_>>()
+                            .join(", ");
+                        writeln!(f, ",")?;
+                        write!(
+                            f,
+                            "{indent}{name}: @escaping ({parameters}) -> Void",
+                            name = callback.name.as_ref()
+                        )?;
+                    }
+                    writeln!(f)?;
+                }
+                writeln!(f, "{indent}) async throws{output}")?;
+            }
+        }
+        writeln!(f, "}}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ast::{ScalarType, TypeReference};
+
+    use super::*;
+
+    fn type_reference(name: &str, is_required: bool) -> CompositeType {
+        CompositeType {
+            scalar: ScalarType::TypeReference(TypeReference {
+                name: Identifier::try_from(name).unwrap(),
+                path: "".into(),
+            }),
+            is_list: false,
+            is_required,
+        }
+    }
+
+    #[test]
+    fn method_facade() {
+        let facade = MethodFacade {
+            name: Identifier::try_from("PortOne").unwrap(),
+            methods: vec![
+                Method {
+                    name: Identifier::try_from("requestPayment").unwrap(),
+                    description: Some(Comment::try_from("결제를 요청합니다.").unwrap()),
+                    input: type_reference("PaymentRequest", true),
+                    output: Some(type_reference("PaymentResponse", false)),
+                    callbacks: vec![],
+                    deprecated: false,
+                    replacement: None,
+                },
+                Method {
+                    name: Identifier::try_from("loadPaymentUI").unwrap(),
+                    description: None,
+                    input: type_reference("LoadPaymentUIRequest", true),
+                    output: None,
+                    callbacks: vec![
+                        Callback {
+                            name: Identifier::try_from("onPaymentSuccess").unwrap(),
+                            description: Some(
+                                Comment::try_from("결제 성공 시 호출됩니다.").unwrap(),
+                            ),
+                            parameters: vec![type_reference("PaymentResponse", true)],
+                            deprecated: false,
+                            replacement: None,
+                        },
+                        Callback {
+                            name: Identifier::try_from("onPaymentFail").unwrap(),
+                            description: None,
+                            parameters: vec![CompositeType {
+                                scalar: ScalarType::Error,
+                                is_list: false,
+                                is_required: true,
+                            }],
+                            deprecated: false,
+                            replacement: None,
+                        },
+                    ],
+                    deprecated: false,
+                    replacement: None,
+                },
+            ],
+        };
+        assert_eq!(
+            facade.to_string(),
+            r#"public protocol PortOne {
+    /// 결제를 요청합니다.
+    func requestPayment(request: PaymentRequest) async throws -> PaymentResponse?
+    /// - Parameters:
+    ///   - onPaymentSuccess: 결제 성공 시 호출됩니다.
+    func loadPaymentUI(
+        request: LoadPaymentUIRequest,
+        onPaymentSuccess: @escaping (PaymentResponse) -> Void,
+        onPaymentFail: @escaping (Error) -> Void
+    ) async throws
+}
+"#
+        );
+    }
+
+    #[test]
+    fn deprecated_method() {
+        let facade = MethodFacade {
+            name: Identifier::try_from("PortOne").unwrap(),
+            methods: vec![Method {
+                name: Identifier::try_from("requestPaymentLegacy").unwrap(),
+                description: None,
+                input: type_reference("PaymentRequest", true),
+                output: None,
+                callbacks: vec![Callback {
+                    name: Identifier::try_from("onDone").unwrap(),
+                    description: Some(Comment::try_from("완료 시 호출됩니다.").unwrap()),
+                    parameters: vec![],
+                    deprecated: true,
+                    replacement: None,
+                }],
+                deprecated: true,
+                replacement: Some("requestPayment".to_string()),
+            }],
+        };
+        assert_eq!(
+            facade.to_string(),
+            r#"public protocol PortOne {
+    /// - Parameters:
+    ///   - onDone: 완료 시 호출됩니다. Deprecated.
+    @available(*, deprecated, message: "Use `requestPayment` instead.")
+    func requestPaymentLegacy(
+        request: PaymentRequest,
+        onDone: @escaping () -> Void
+    ) async throws
+}
+"#
+        );
+    }
+}
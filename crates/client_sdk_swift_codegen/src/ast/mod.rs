@@ -1,18 +1,43 @@
 use std::fmt;
 
 mod r#enum;
+mod error;
 mod ident;
 mod intersection;
+mod method;
 mod object;
 mod union;
 
 use client_sdk_utils::{MdastNodeExt, ToMdastExt};
 pub use r#enum::*;
+pub use error::*;
 pub use ident::*;
 pub use intersection::*;
+pub use method::*;
 pub use object::*;
 pub use union::*;
 
+/// 더 이상 사용되지 않는 항목에 붙이는 속성
+///
+/// 대체 항목이 있으면 메시지에서 안내합니다.
+pub struct DeprecatedAttribute<'a>(pub Option<&'a str>);
+
+impl fmt::Display for DeprecatedAttribute<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.0 {
+            Some(replacement) => write!(
+                f,
+                "@available(*, deprecated, message: {})",
+                string_literal(&format!("Use `{replacement}` instead."))
+            ),
+            None => write!(
+                f,
+                "@available(*, deprecated, message: \"This API is deprecated and will be removed in a future version.\")"
+            ),
+        }
+    }
+}
+
 #[derive(Debug, Clone, Copy)]
 pub struct Indent(pub usize);
 
@@ -59,23 +84,61 @@ pub struct TypeReference {
     pub name: Identifier,
 }
 
+/// 값을 Swift 문자열 리터럴로 변환합니다.
+pub fn string_literal(value: &str) -> String {
+    let escaped = value
+        .replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n");
+    format!("\"{escaped}\"")
+}
+
 #[derive(Debug, Clone)]
 pub enum ScalarType {
     Int,
+    Double,
+    /// 문자열로 직렬화되는 `Decimal`
+    Decimal,
     Bool,
     Json,
     String,
+    /// 콜백으로 전달되는 에러
+    Error,
     TypeReference(TypeReference),
+    /// 문자열 키를 가지는 딕셔너리
+    Map(Box<ScalarType>),
+    /// 배열의 항목으로 쓰이는 배열
+    List(Box<ScalarType>),
 }
 
 impl ScalarType {
-    pub fn to_swift_type(&self) -> &str {
+    pub fn to_swift_type(&self) -> String {
+        match self {
+            ScalarType::Int => "Int".to_string(),
+            ScalarType::Double => "Double".to_string(),
+            ScalarType::Decimal => "Decimal".to_string(),
+            ScalarType::Bool => "Bool".to_string(),
+            ScalarType::Json => "JSONValue".to_string(),
+            ScalarType::String => "String".to_string(),
+            ScalarType::Error => "Error".to_string(),
+            ScalarType::TypeReference(TypeReference { name, .. }) => name.as_ref().to_string(),
+            ScalarType::Map(values) => format!("[String: {}]", values.to_swift_type()),
+            ScalarType::List(items) => format!("[{}]", items.to_swift_type()),
+        }
+    }
+
+    /// 값의 타입 (`Map`이나 `List`이면 원소의 타입)
+    pub fn value_scalar(&self) -> &ScalarType {
         match self {
-            ScalarType::Int => "Int",
-            ScalarType::Bool => "Bool",
-            ScalarType::Json => "JSONValue",
-            ScalarType::String => "String",
-            ScalarType::TypeReference(TypeReference { name, .. }) => name.as_ref(),
+            ScalarType::Map(values) | ScalarType::List(values) => values.value_scalar(),
+            scalar => scalar,
+        }
+    }
+
+    pub fn value_scalar_mut(&mut self) -> &mut ScalarType {
+        match self {
+            ScalarType::Map(values) | ScalarType::List(values) => values.value_scalar_mut(),
+            scalar => scalar,
         }
     }
 }
@@ -86,3 +149,27 @@ pub struct CompositeType {
     pub is_list: bool,
     pub is_required: bool,
 }
+
+impl fmt::Display for CompositeType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let nullable = if self.is_required { "" } else { "?" };
+        if self.is_list {
+            write!(f, "[{}]{nullable}", self.scalar.to_swift_type())
+        } else {
+            write!(f, "{}{nullable}", self.scalar.to_swift_type())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn deprecated_attribute_escapes_replacement() {
+        assert_eq!(
+            DeprecatedAttribute(Some("pay(\"amount\")")).to_string(),
+            r#"@available(*, deprecated, message: "Use `pay(\"amount\")` instead.")"#
+        );
+    }
+}
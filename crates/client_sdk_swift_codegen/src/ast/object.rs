@@ -1,6 +1,6 @@
 use std::fmt;
 
-use super::{Comment, CompositeType, Identifier, Indent, UnionParent};
+use super::{Comment, CompositeType, DeprecatedAttribute, Identifier, Indent, UnionParent};
 
 #[derive(Debug, Clone)]
 pub struct Object {
@@ -9,6 +9,7 @@ pub struct Object {
     pub fields: Vec<ObjectField>,
     pub union_parents: Vec<UnionParent>,
     pub is_one_of: bool,
+    pub deprecated: bool,
 }
 
 impl fmt::Display for Object {
@@ -18,6 +19,9 @@ impl fmt::Display for Object {
                 writeln!(f, "/// {line}")?;
             }
         }
+        if self.deprecated {
+            writeln!(f, "{}", DeprecatedAttribute(None))?;
+        }
 
         if self.fields.is_empty() && !self.is_one_of {
             // Empty object case
@@ -49,8 +53,11 @@ impl fmt::Display for Object {
                     let field_type = if field.value_type.is_list {
                         format!("[{}]", field.value_type.scalar.to_swift_type())
                     } else {
-                        field.value_type.scalar.to_swift_type().to_string()
+                        field.value_type.scalar.to_swift_type()
                     };
+                    if field.deprecated {
+                        writeln!(f, "{indent}{}", DeprecatedAttribute(None))?;
+                    }
                     writeln!(
                         f,
                         "{indent}case {field_name}({field_type})",
@@ -88,7 +95,7 @@ impl fmt::Display for Object {
                         let field_type = if field.value_type.is_list {
                             format!("[{}]", field.value_type.scalar.to_swift_type())
                         } else {
-                            field.value_type.scalar.to_swift_type().to_string()
+                            field.value_type.scalar.to_swift_type()
                         };
                         if i == 0 {
                             writeln!(
@@ -210,15 +217,11 @@ impl fmt::Display for Object {
                     } else {
                         "?"
                     };
-                    let default_value = if field.value_type.is_required {
-                        ""
-                    } else {
-                        " = nil"
-                    };
+                    let default_value = field.default_argument();
                     let field_type = if field.value_type.is_list {
                         format!("[{}]", field.value_type.scalar.to_swift_type())
                     } else {
-                        field.value_type.scalar.to_swift_type().to_string()
+                        field.value_type.scalar.to_swift_type()
                     };
                     write!(
                         f,
@@ -235,18 +238,74 @@ impl fmt::Display for Object {
                     }
                 }
                 writeln!(f, "{indent}}}")?;
+
+                if self.fields.iter().any(|field| field.hide_if_empty) {
+                    writeln!(f)?;
+                    write!(f, "{}", EncodeFields(&self.fields))?;
+                }
             }
             writeln!(f, "}}")
         }
     }
 }
 
+/// `hideIfEmpty` 필드를 생략하기 위한 `encode(to:)` 구현
+pub(super) struct EncodeFields<'a>(pub &'a [ObjectField]);
+
+impl fmt::Display for EncodeFields<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let indent = Indent(1);
+        writeln!(
+            f,
+            "{indent}public func encode(to encoder: Encoder) throws {{"
+        )?;
+        {
+            let indent = Indent(2);
+            writeln!(
+                f,
+                "{indent}var container = encoder.container(keyedBy: CodingKeys.self)"
+            )?;
+            for field in self.0 {
+                let method = if field.hide_if_empty {
+                    "encodeIfNotEmpty"
+                } else if field.value_type.is_required {
+                    "encode"
+                } else {
+                    "encodeIfPresent"
+                };
+                writeln!(
+                    f,
+                    "{indent}try container.{method}({field_name}, forKey: .{field_name})",
+                    field_name = field.name.as_ref()
+                )?;
+            }
+        }
+        writeln!(f, "{indent}}}")
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct ObjectField {
     pub name: Identifier,
     pub serialized_name: String,
     pub value_type: CompositeType,
     pub description: Option<Comment>,
+    /// 값이 비어있을 때 직렬화 결과에서 제외할지 여부
+    pub hide_if_empty: bool,
+    pub deprecated: bool,
+    /// 생성자 인자의 기본값으로 사용할 식
+    pub default_value: Option<String>,
+}
+
+impl ObjectField {
+    /// 생성자 인자의 기본값 (지정된 기본값이 없으면 optional 필드만 ` = nil`)
+    pub(super) fn default_argument(&self) -> String {
+        match (&self.default_value, self.value_type.is_required) {
+            (Some(default_value), _) => format!(" = {default_value}"),
+            (None, true) => String::new(),
+            (None, false) => " = nil".to_string(),
+        }
+    }
 }
 
 impl fmt::Display for ObjectField {
@@ -255,9 +314,12 @@ impl fmt::Display for ObjectField {
         let field_type = if self.value_type.is_list {
             format!("[{}]", self.value_type.scalar.to_swift_type())
         } else {
-            self.value_type.scalar.to_swift_type().to_string()
+            self.value_type.scalar.to_swift_type()
         };
 
+        if self.deprecated {
+            write!(f, "{} ", DeprecatedAttribute(None))?;
+        }
         write!(
             f,
             "public let {name}: {field_type}{nullable}",
@@ -280,6 +342,7 @@ mod tests {
             fields: vec![],
             is_one_of: false,
             union_parents: vec![],
+            deprecated: false,
         };
         assert_eq!(
             object.to_string(),
@@ -308,6 +371,9 @@ mod tests {
                         is_required: false,
                     },
                     description: None,
+                    hide_if_empty: false,
+                    deprecated: false,
+                    default_value: None,
                 },
                 ObjectField {
                     name: Identifier::try_from("addressLine1").unwrap(),
@@ -318,6 +384,9 @@ mod tests {
                         is_required: true,
                     },
                     description: Some(Comment::try_from("**일반주소**").unwrap()),
+                    hide_if_empty: false,
+                    deprecated: false,
+                    default_value: None,
                 },
                 ObjectField {
                     name: Identifier::try_from("addressLine2").unwrap(),
@@ -328,6 +397,9 @@ mod tests {
                         is_required: true,
                     },
                     description: Some(Comment::try_from("**상세주소**").unwrap()),
+                    hide_if_empty: false,
+                    deprecated: false,
+                    default_value: None,
                 },
                 ObjectField {
                     name: Identifier::try_from("city").unwrap(),
@@ -338,6 +410,9 @@ mod tests {
                         is_required: false,
                     },
                     description: Some(Comment::try_from("**도시**").unwrap()),
+                    hide_if_empty: false,
+                    deprecated: false,
+                    default_value: None,
                 },
                 ObjectField {
                     name: Identifier::try_from("province").unwrap(),
@@ -348,10 +423,14 @@ mod tests {
                         is_required: false,
                     },
                     description: Some(Comment::try_from("**주, 도, 시**").unwrap()),
+                    hide_if_empty: false,
+                    deprecated: false,
+                    default_value: None,
                 },
             ],
             is_one_of: false,
             union_parents: vec![],
+            deprecated: false,
         };
         assert_eq!(
             object.to_string(),
@@ -397,6 +476,9 @@ public struct Address: Codable {
                         Comment::try_from("**구매자가 선택할 수 없도록 고정된 할부 개월수**")
                             .unwrap(),
                     ),
+                    hide_if_empty: false,
+                    deprecated: false,
+                    default_value: None,
                 },
                 ObjectField {
                     name: Identifier::try_from("availableMonthList").unwrap(),
@@ -410,10 +492,14 @@ public struct Address: Codable {
                         Comment::try_from("**구매자가 선택할 수 있는 할부 개월수 리스트**")
                             .unwrap(),
                     ),
+                    hide_if_empty: false,
+                    deprecated: false,
+                    default_value: None,
                 },
             ],
             is_one_of: true,
             union_parents: vec![],
+            deprecated: false,
         };
         assert_eq!(
             object.to_string(),
@@ -469,6 +555,9 @@ public enum MonthOption: Codable {
                         is_required: true,
                     },
                     description: None,
+                    hide_if_empty: false,
+                    deprecated: false,
+                    default_value: None,
                 },
                 ObjectField {
                     name: Identifier::try_from("metadata").unwrap(),
@@ -479,6 +568,9 @@ public enum MonthOption: Codable {
                         is_required: true,
                     },
                     description: Some(Comment::try_from("**추가 메타데이터**").unwrap()),
+                    hide_if_empty: false,
+                    deprecated: false,
+                    default_value: None,
                 },
                 ObjectField {
                     name: Identifier::try_from("tags").unwrap(),
@@ -489,10 +581,14 @@ public enum MonthOption: Codable {
                         is_required: false,
                     },
                     description: None,
+                    hide_if_empty: false,
+                    deprecated: false,
+                    default_value: None,
                 },
             ],
             is_one_of: false,
             union_parents: vec![],
+            deprecated: false,
         };
         assert_eq!(
             object.to_string(),
@@ -509,6 +605,98 @@ public struct CustomData: Codable {
         self.tags = tags
     }
 }
+"#
+        );
+    }
+
+    #[test]
+    fn object_with_hide_if_empty_fields() {
+        let field =
+            |name: &str, is_list: bool, is_required: bool, hide_if_empty: bool| ObjectField {
+                name: Identifier::try_from(name).unwrap(),
+                serialized_name: name.to_string(),
+                value_type: CompositeType {
+                    scalar: ScalarType::String,
+                    is_list,
+                    is_required,
+                },
+                description: None,
+                hide_if_empty,
+                deprecated: false,
+                default_value: None,
+            };
+        let object = Object {
+            name: Identifier::try_from("Request").unwrap(),
+            description: None,
+            fields: vec![
+                field("id", false, true, false),
+                field("city", false, false, false),
+                field("products", true, true, true),
+                field("tags", true, false, true),
+            ],
+            is_one_of: false,
+            union_parents: vec![],
+            deprecated: false,
+        };
+        assert_eq!(
+            object.to_string(),
+            r#"public struct Request: Codable {
+    public let id: String
+    public let city: String?
+    public let products: [String]
+    public let tags: [String]?
+
+    public init(id: String, city: String? = nil, products: [String], tags: [String]? = nil) {
+        self.id = id
+        self.city = city
+        self.products = products
+        self.tags = tags
+    }
+
+    public func encode(to encoder: Encoder) throws {
+        var container = encoder.container(keyedBy: CodingKeys.self)
+        try container.encode(id, forKey: .id)
+        try container.encodeIfPresent(city, forKey: .city)
+        try container.encodeIfNotEmpty(products, forKey: .products)
+        try container.encodeIfNotEmpty(tags, forKey: .tags)
+    }
+}
+"#
+        );
+    }
+
+    #[test]
+    fn deprecated_object() {
+        let object = Object {
+            name: Identifier::try_from("Customer").unwrap(),
+            description: None,
+            fields: vec![ObjectField {
+                name: Identifier::try_from("birthYear").unwrap(),
+                serialized_name: "birthYear".to_string(),
+                value_type: CompositeType {
+                    scalar: ScalarType::String,
+                    is_list: false,
+                    is_required: false,
+                },
+                description: None,
+                hide_if_empty: false,
+                deprecated: true,
+                default_value: None,
+            }],
+            is_one_of: false,
+            union_parents: vec![],
+            deprecated: true,
+        };
+        assert_eq!(
+            object.to_string(),
+            r#"@available(*, deprecated, message: "This API is deprecated and will be removed in a future version.")
+public struct Customer: Codable {
+    @available(*, deprecated, message: "This API is deprecated and will be removed in a future version.") public let birthYear: String?
+
+    public init(birthYear: String? = nil) {
+        self.birthYear = birthYear
+    }
+}
 "#
         );
     }
@@ -2,7 +2,7 @@ use std::fmt;
 
 use crate::ast::Indent;
 
-use super::{Comment, Identifier, TypeReference};
+use super::{Comment, DeprecatedAttribute, Identifier, ScalarType, TypeReference, string_literal};
 
 #[derive(Debug, Clone)]
 pub enum UnionParent {
@@ -17,15 +17,26 @@ pub struct Union {
     pub name: Identifier,
     pub description: Option<Comment>,
     pub variants: Vec<UnionVariant>,
+    pub deprecated: bool,
 }
 
 #[derive(Debug, Clone)]
 pub struct UnionVariant {
     pub name: Identifier,
-    pub type_name: TypeReference,
+    pub value_type: UnionVariantType,
     pub description: Option<Comment>,
 }
 
+#[derive(Debug, Clone)]
+pub enum UnionVariantType {
+    /// 리소스로 생성된 타입
+    TypeReference(TypeReference),
+    /// 원시 타입의 값
+    Scalar(ScalarType),
+    /// 값이 고정된 문자열 리터럴
+    Literal(String),
+}
+
 impl fmt::Display for Union {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         // Comments
@@ -34,6 +45,9 @@ impl fmt::Display for Union {
                 writeln!(f, "/// {line}")?;
             }
         }
+        if self.deprecated {
+            writeln!(f, "{}", DeprecatedAttribute(None))?;
+        }
 
         // Enum declaration with associated values
         writeln!(
@@ -51,12 +65,20 @@ impl fmt::Display for Union {
                         writeln!(f, "{indent}/// {line}")?;
                     }
                 }
-                writeln!(
-                    f,
-                    "{indent}case {variant_name}({type_name})",
-                    variant_name = decapitalize_first(variant.name.as_ref()),
-                    type_name = variant.type_name.name.as_ref(),
-                )?;
+                let variant_name = decapitalize_first(variant.name.as_ref());
+                match &variant.value_type {
+                    UnionVariantType::TypeReference(type_name) => writeln!(
+                        f,
+                        "{indent}case {variant_name}({type_name})",
+                        type_name = type_name.name.as_ref(),
+                    )?,
+                    UnionVariantType::Scalar(scalar) => writeln!(
+                        f,
+                        "{indent}case {variant_name}({type_name})",
+                        type_name = scalar.to_swift_type(),
+                    )?,
+                    UnionVariantType::Literal(_) => writeln!(f, "{indent}case {variant_name}")?,
+                }
             }
 
             writeln!(f)?;
@@ -67,21 +89,40 @@ impl fmt::Display for Union {
                 let indent = Indent(2);
                 for (i, variant) in self.variants.iter().enumerate() {
                     let variant_name = decapitalize_first(variant.name.as_ref());
-                    let type_name = variant.type_name.name.as_ref();
+                    let (condition, assignment) = match &variant.value_type {
+                        UnionVariantType::TypeReference(type_name) => (
+                            format!(
+                                "let value = try? {type_name}(from: decoder)",
+                                type_name = type_name.name.as_ref()
+                            ),
+                            format!(".{variant_name}(value)"),
+                        ),
+                        // `decimal`은 문자열로 전달됨
+                        UnionVariantType::Scalar(ScalarType::Decimal) => (
+                            "let string = try? String(from: decoder), let value = Decimal(string: string)"
+                                .to_string(),
+                            format!(".{variant_name}(value)"),
+                        ),
+                        UnionVariantType::Scalar(scalar) => (
+                            format!(
+                                "let value = try? {type_name}(from: decoder)",
+                                type_name = scalar.to_swift_type()
+                            ),
+                            format!(".{variant_name}(value)"),
+                        ),
+                        UnionVariantType::Literal(value) => (
+                            format!("(try? String(from: decoder)) == {}", string_literal(value)),
+                            format!(".{variant_name}"),
+                        ),
+                    };
                     if i == 0 {
-                        writeln!(
-                            f,
-                            "{indent}if let value = try? {type_name}(from: decoder) {{"
-                        )?;
+                        writeln!(f, "{indent}if {condition} {{")?;
                     } else {
-                        writeln!(
-                            f,
-                            "{indent}}} else if let value = try? {type_name}(from: decoder) {{"
-                        )?;
+                        writeln!(f, "{indent}}} else if {condition} {{")?;
                     }
                     {
                         let indent = Indent(3);
-                        writeln!(f, "{indent}self = .{variant_name}(value)")?;
+                        writeln!(f, "{indent}self = {assignment}")?;
                     }
                 }
                 writeln!(f, "{indent}}} else {{")?;
@@ -107,10 +148,29 @@ impl fmt::Display for Union {
                 writeln!(f, "{indent}switch self {{")?;
                 for variant in self.variants.iter() {
                     let variant_name = decapitalize_first(variant.name.as_ref());
-                    writeln!(f, "{indent}case .{variant_name}(let value):")?;
-                    {
-                        let indent = Indent(3);
-                        writeln!(f, "{indent}try value.encode(to: encoder)")?;
+                    match &variant.value_type {
+                        UnionVariantType::Literal(value) => {
+                            writeln!(f, "{indent}case .{variant_name}:")?;
+                            let indent = Indent(3);
+                            writeln!(
+                                f,
+                                "{indent}try {}.encode(to: encoder)",
+                                string_literal(value)
+                            )?;
+                        }
+                        UnionVariantType::Scalar(ScalarType::Decimal) => {
+                            writeln!(f, "{indent}case .{variant_name}(let value):")?;
+                            let indent = Indent(3);
+                            writeln!(
+                                f,
+                                "{indent}try NSDecimalNumber(decimal: value).stringValue.encode(to: encoder)"
+                            )?;
+                        }
+                        _ => {
+                            writeln!(f, "{indent}case .{variant_name}(let value):")?;
+                            let indent = Indent(3);
+                            writeln!(f, "{indent}try value.encode(to: encoder)")?;
+                        }
                     }
                 }
                 writeln!(f, "{indent}}}")?;
@@ -141,21 +201,22 @@ mod tests {
             variants: vec![
                 UnionVariant {
                     name: Identifier::try_from("PaymentUIType").unwrap(),
-                    type_name: TypeReference {
+                    value_type: UnionVariantType::TypeReference(TypeReference {
                         name: Identifier::try_from("PaymentUIType").unwrap(),
                         path: "".into(),
-                    },
+                    }),
                     description: None,
                 },
                 UnionVariant {
                     name: Identifier::try_from("IssueBillingKeyUIType").unwrap(),
-                    type_name: TypeReference {
+                    value_type: UnionVariantType::TypeReference(TypeReference {
                         name: Identifier::try_from("IssueBillingKeyUIType").unwrap(),
                         path: "".into(),
-                    },
+                    }),
                     description: None,
                 },
             ],
+            deprecated: false,
         };
         assert_eq!(
             union.to_string(),
@@ -182,6 +243,64 @@ mod tests {
         }
     }
 }
+"#
+        );
+    }
+
+    #[test]
+    fn union_with_literal_and_scalar_variants() {
+        let union = Union {
+            name: Identifier::try_from("CardInstallment").unwrap(),
+            description: None,
+            variants: vec![
+                UnionVariant {
+                    name: Identifier::try_from("union0").unwrap(),
+                    value_type: UnionVariantType::Literal("NONE".to_string()),
+                    description: None,
+                },
+                UnionVariant {
+                    name: Identifier::try_from("union1").unwrap(),
+                    value_type: UnionVariantType::Scalar(ScalarType::Int),
+                    description: None,
+                },
+                UnionVariant {
+                    name: Identifier::try_from("union2").unwrap(),
+                    value_type: UnionVariantType::Scalar(ScalarType::Decimal),
+                    description: None,
+                },
+            ],
+            deprecated: false,
+        };
+        assert_eq!(
+            union.to_string(),
+            r#"public enum CardInstallment: Codable {
+    case union0
+    case union1(Int)
+    case union2(Decimal)
+
+    public init(from decoder: Decoder) throws {
+        if (try? String(from: decoder)) == "NONE" {
+            self = .union0
+        } else if let value = try? Int(from: decoder) {
+            self = .union1(value)
+        } else if let string = try? String(from: decoder), let value = Decimal(string: string) {
+            self = .union2(value)
+        } else {
+            throw DecodingError.dataCorrupted(DecodingError.Context(codingPath: decoder.codingPath, debugDescription: "No matching type found"))
+        }
+    }
+
+    public func encode(to encoder: Encoder) throws {
+        switch self {
+        case .union0:
+            try "NONE".encode(to: encoder)
+        case .union1(let value):
+            try value.encode(to: encoder)
+        case .union2(let value):
+            try NSDecimalNumber(decimal: value).stringValue.encode(to: encoder)
+        }
+    }
+}
 "#
         );
     }
@@ -1,10 +1,14 @@
 use std::{collections::HashMap, path::Path};
 
 use ast::{
-    Comment, CompositeType, Enum, EnumVariant, Identifier, Intersection, IntersectionConstituent,
-    Object, ObjectField, ScalarType, TypeReference, Union, UnionParent, UnionVariant,
+    BASE_ERROR_NAME, Callback, Comment, CompositeType, Enum, EnumVariant, ErrorClass, Identifier,
+    Intersection, IntersectionConstituent, Method, MethodFacade, Object, ObjectField, ScalarType,
+    TypeReference, Union, UnionParent, UnionVariant, UnionVariantType, string_literal,
+};
+use client_sdk_schema::{
+    MOBILE_RESOURCE_ROOTS, Parameter, ParameterExt, ParameterType, RESOURCE_INDEX, Resource,
+    ResourceRef,
 };
-use client_sdk_schema::{Parameter, ParameterType, RESOURCE_INDEX, Resource, ResourceRef};
 use client_sdk_utils::write_generated_file;
 use convert_case::{Case, Casing};
 
@@ -15,6 +19,7 @@ enum Entity {
     Enum(Enum),
     Union(Union),
     Intersection(Intersection),
+    Error(ErrorClass),
 }
 
 impl Entity {
@@ -24,6 +29,7 @@ impl Entity {
             Entity::Enum(e) => e.name.as_ref(),
             Entity::Union(u) => u.name.as_ref(),
             Entity::Intersection(i) => i.name.as_ref(),
+            Entity::Error(e) => e.name.as_ref(),
         }
     }
 
@@ -34,6 +40,7 @@ impl Entity {
             Entity::Enum(e) => e.name = name,
             Entity::Union(u) => u.name = name,
             Entity::Intersection(i) => i.name = name,
+            Entity::Error(e) => e.name = name,
         }
     }
 }
@@ -77,11 +84,37 @@ impl ResourceProcessor {
         })
     }
 
+    /// 배열의 항목이나 맵의 값으로 쓰이는 타입
+    fn build_element_scalar(parameter: &Parameter) -> ScalarType {
+        match &parameter.r#type {
+            ParameterType::String { .. } | ParameterType::StringLiteral { .. } => {
+                ScalarType::String
+            }
+            ParameterType::Integer => ScalarType::Int,
+            ParameterType::Number { .. } => ScalarType::Double,
+            ParameterType::Decimal { .. } => ScalarType::Decimal,
+            ParameterType::Boolean => ScalarType::Bool,
+            ParameterType::ResourceRef(resource_ref) => {
+                ScalarType::TypeReference(Self::resource_ref_to_type_reference(resource_ref))
+            }
+            ParameterType::Json => ScalarType::Json,
+            ParameterType::Enum { .. } => ScalarType::String,
+            ParameterType::Array { items, .. } => {
+                ScalarType::List(Box::new(Self::build_element_scalar(items)))
+            }
+            ParameterType::Map { values } => {
+                ScalarType::Map(Box::new(Self::build_element_scalar(values)))
+            }
+            _ => unreachable!(),
+        }
+    }
+
     fn build_field(name: &str, parameter: &Parameter) -> ObjectField {
         let field_name: Identifier = name.to_case(Case::Camel).try_into().unwrap();
         let is_required = !parameter.optional;
+        let deprecated = parameter.deprecated();
         let value_type = match &parameter.r#type {
-            ParameterType::String | ParameterType::StringLiteral { .. } => CompositeType {
+            ParameterType::String { .. } | ParameterType::StringLiteral { .. } => CompositeType {
                 scalar: ScalarType::String,
                 is_list: false,
                 is_required,
@@ -91,6 +124,16 @@ impl ResourceProcessor {
                 is_list: false,
                 is_required,
             },
+            ParameterType::Number { .. } => CompositeType {
+                scalar: ScalarType::Double,
+                is_list: false,
+                is_required,
+            },
+            ParameterType::Decimal { .. } => CompositeType {
+                scalar: ScalarType::Decimal,
+                is_list: false,
+                is_required,
+            },
             ParameterType::Boolean => CompositeType {
                 scalar: ScalarType::Bool,
                 is_list: false,
@@ -101,6 +144,11 @@ impl ResourceProcessor {
                 is_list: false,
                 is_required,
             },
+            ParameterType::Error { .. } => CompositeType {
+                scalar: ScalarType::Error,
+                is_list: false,
+                is_required,
+            },
             ParameterType::Enum { .. } => CompositeType {
                 scalar: ScalarType::String,
                 is_list: false,
@@ -109,27 +157,18 @@ impl ResourceProcessor {
             ParameterType::Array {
                 items,
                 hide_if_empty: _,
-            } => {
-                let scalar = match &items.r#type {
-                    ParameterType::String | ParameterType::StringLiteral { .. } => {
-                        ScalarType::String
-                    }
-                    ParameterType::Integer => ScalarType::Int,
-                    ParameterType::Boolean => ScalarType::Bool,
-                    ParameterType::ResourceRef(resource_ref) => ScalarType::TypeReference(
-                        Self::resource_ref_to_type_reference(resource_ref),
-                    ),
-                    ParameterType::Json => ScalarType::Json,
-                    ParameterType::Enum { .. } => ScalarType::String,
-                    _ => unreachable!(),
-                };
-                CompositeType {
-                    scalar,
-                    is_list: true,
-                    is_required,
-                }
-            }
+            } => CompositeType {
+                scalar: Self::build_element_scalar(items),
+                is_list: true,
+                is_required,
+            },
+            ParameterType::Map { values } => CompositeType {
+                scalar: ScalarType::Map(Box::new(Self::build_element_scalar(values))),
+                is_list: false,
+                is_required,
+            },
             ParameterType::ResourceRef(resource_ref) => {
+                let field_parameter = parameter;
                 return RESOURCE_INDEX.with(|index| {
                     let mut resource_ref = resource_ref;
                     loop {
@@ -140,7 +179,8 @@ impl ResourceProcessor {
                             | ParameterType::Enum { .. }
                             | ParameterType::Union { .. }
                             | ParameterType::OneOf { .. }
-                            | ParameterType::Intersection { .. } => {
+                            | ParameterType::Intersection { .. }
+                            | ParameterType::Error { .. } => {
                                 let value_type = CompositeType {
                                     scalar: ScalarType::TypeReference(
                                         Self::resource_ref_to_type_reference(resource_ref),
@@ -148,6 +188,8 @@ impl ResourceProcessor {
                                     is_list: false,
                                     is_required,
                                 };
+                                let default_value =
+                                    Self::build_default_value(field_parameter, &value_type);
                                 break ObjectField {
                                     name: field_name,
                                     serialized_name: name.to_string(),
@@ -156,6 +198,22 @@ impl ResourceProcessor {
                                         .description
                                         .clone()
                                         .map(|d| Comment::try_from(d).unwrap()),
+                                    // 직렬화 결과가 Map인 타입만 비어있는지 확인할 수 있음
+                                    hide_if_empty: matches!(
+                                        parameter.r#type,
+                                        ParameterType::Object {
+                                            hide_if_empty: true,
+                                            ..
+                                        } | ParameterType::OneOf {
+                                            hide_if_empty: true,
+                                            ..
+                                        } | ParameterType::Intersection {
+                                            hide_if_empty: true,
+                                            ..
+                                        }
+                                    ),
+                                    deprecated,
+                                    default_value,
                                 };
                             }
                             ParameterType::ResourceRef(r) => {
@@ -164,6 +222,13 @@ impl ResourceProcessor {
                             _ => {
                                 let mut field = Self::build_field(name, parameter);
                                 field.value_type.is_required = is_required;
+                                field.deprecated = deprecated;
+                                if field_parameter.default.is_some() {
+                                    field.default_value = Self::build_default_value(
+                                        field_parameter,
+                                        &field.value_type,
+                                    );
+                                }
                                 break field;
                             }
                         }
@@ -172,11 +237,59 @@ impl ResourceProcessor {
             }
             _ => unreachable!("{:#?}", parameter.r#type),
         };
+        let default_value = Self::build_default_value(parameter, &value_type);
         ObjectField {
             name: field_name,
             serialized_name: name.to_string(),
             value_type,
             description: Self::build_field_description(parameter),
+            hide_if_empty: matches!(
+                parameter.r#type,
+                ParameterType::Array {
+                    hide_if_empty: true,
+                    ..
+                }
+            ),
+            deprecated,
+            default_value,
+        }
+    }
+
+    /// 파라미터의 기본값을 이니셜라이저의 기본 인자로 사용할 Swift 식으로 변환합니다.
+    fn build_default_value(parameter: &Parameter, value_type: &CompositeType) -> Option<String> {
+        let default = parameter.default.as_deref()?;
+        if value_type.is_list {
+            return None;
+        }
+        match &value_type.scalar {
+            ScalarType::String => default.as_str().map(string_literal),
+            ScalarType::Int => default.as_i64().map(|value| value.to_string()),
+            ScalarType::Double => default.as_f64().map(|value| format!("{value:?}")),
+            ScalarType::Decimal => default
+                .as_str()
+                .map(|value| format!("Decimal(string: \"{value}\")!")),
+            ScalarType::Bool => default.as_bool().map(|value| value.to_string()),
+            ScalarType::TypeReference(TypeReference { name, .. }) => {
+                let variant = Self::enum_variant_name(&parameter.r#type, default.as_str()?)?;
+                Some(format!("{}.{}", name.as_ref(), variant.as_ref()))
+            }
+            ScalarType::Json | ScalarType::Error | ScalarType::Map(_) | ScalarType::List(_) => None,
+        }
+    }
+
+    /// 기본값에 해당하는 enum variant의 이름을 찾습니다.
+    fn enum_variant_name(r#type: &ParameterType, value: &str) -> Option<Identifier> {
+        match r#type {
+            ParameterType::Enum { variants, .. } => {
+                let variant = variants.get(value)?;
+                let name = variant.alias.as_deref().unwrap_or(value);
+                Identifier::try_from(name).ok()
+            }
+            ParameterType::ResourceRef(resource_ref) => RESOURCE_INDEX.with(|index| {
+                let parameter = index.get(resource_ref.resource_ref())?;
+                Self::enum_variant_name(&parameter.r#type, value)
+            }),
+            _ => None,
         }
     }
 
@@ -204,6 +317,15 @@ impl ResourceProcessor {
             }
         }
 
+        let constraints = parameter.r#type.constraints();
+        if !constraints.is_empty() {
+            let constraint_lines: Vec<String> = constraints
+                .iter()
+                .map(|constraint| format!("- {constraint}"))
+                .collect();
+            desc_parts.push(constraint_lines.join("\n"));
+        }
+
         if desc_parts.is_empty() {
             None
         } else {
@@ -249,8 +371,11 @@ impl ResourceProcessor {
                     name: parent.name.clone(),
                 };
                 for variant in parent.variants.iter() {
+                    let UnionVariantType::TypeReference(type_name) = &variant.value_type else {
+                        continue;
+                    };
                     union_parents
-                        .entry(variant.type_name.path.clone())
+                        .entry(type_name.path.clone())
                         .or_insert(vec![])
                         .push(UnionParent::Union {
                             parent: parent_ref.clone(),
@@ -266,6 +391,7 @@ impl ResourceProcessor {
                 | Entity::Enum(Enum { union_parents, .. }) => {
                     *union_parents = parents;
                 }
+                Entity::Error(_) => {}
                 _ => unreachable!(),
             }
         }
@@ -293,6 +419,7 @@ impl ResourceProcessor {
                 fields: Self::build_field_list(properties.iter()),
                 is_one_of: false,
                 union_parents: vec![],
+                deprecated: parameter.deprecated,
             })),
             ParameterType::EmptyObject => Some(Entity::Object(Object {
                 name: name.clone(),
@@ -303,6 +430,7 @@ impl ResourceProcessor {
                 fields: vec![],
                 is_one_of: false,
                 union_parents: vec![],
+                deprecated: parameter.deprecated,
             })),
             ParameterType::Enum { variants, .. } => Some(Entity::Enum(Enum {
                 name: name.clone(),
@@ -323,9 +451,12 @@ impl ResourceProcessor {
                             .description
                             .clone()
                             .map(|d| Comment::try_from(d).unwrap()),
+                        deprecated: variant.deprecated,
+                        replacement: variant.replacement.clone(),
                     })
                     .collect(),
                 union_parents: vec![],
+                deprecated: parameter.deprecated,
             })),
             ParameterType::OneOf {
                 properties,
@@ -339,6 +470,7 @@ impl ResourceProcessor {
                 fields: Self::build_field_list(properties.iter()),
                 is_one_of: true,
                 union_parents: vec![],
+                deprecated: parameter.deprecated,
             })),
             ParameterType::Union {
                 types,
@@ -351,21 +483,40 @@ impl ResourceProcessor {
                     .map(|d| Comment::try_from(d).unwrap()),
                 variants: types
                     .iter()
-                    .map(|parameter| match &parameter.r#type {
-                        ParameterType::ResourceRef(resource_ref) => {
-                            let type_reference = Self::resource_ref_to_type_reference(resource_ref);
-                            UnionVariant {
-                                name: type_reference.name.as_ref().try_into().unwrap(),
-                                description: parameter
-                                    .description
-                                    .clone()
-                                    .map(|d| Comment::try_from(d).unwrap()),
-                                type_name: type_reference,
+                    .enumerate()
+                    .map(|(i, parameter)| {
+                        let description = parameter
+                            .description
+                            .clone()
+                            .map(|d| Comment::try_from(d).unwrap());
+                        // 리소스가 아닌 멤버는 TypeScript와 같이 순서로 이름을 지음
+                        let name = Identifier::try_from(format!("union{i}")).unwrap();
+                        match &parameter.r#type {
+                            ParameterType::ResourceRef(resource_ref) => {
+                                let type_reference =
+                                    Self::resource_ref_to_type_reference(resource_ref);
+                                UnionVariant {
+                                    name: type_reference.name.as_ref().try_into().unwrap(),
+                                    description,
+                                    value_type: UnionVariantType::TypeReference(type_reference),
+                                }
                             }
+                            ParameterType::StringLiteral { value } => UnionVariant {
+                                name,
+                                description,
+                                value_type: UnionVariantType::Literal(value.clone()),
+                            },
+                            _ => UnionVariant {
+                                name,
+                                description,
+                                value_type: UnionVariantType::Scalar(Self::build_element_scalar(
+                                    parameter,
+                                )),
+                            },
                         }
-                        _ => unreachable!(),
                     })
                     .collect(),
+                deprecated: parameter.deprecated,
             })),
             ParameterType::Intersection {
                 types,
@@ -411,8 +562,21 @@ impl ResourceProcessor {
                     constituents,
                     fields: all_fields,
                     union_parents: vec![],
+                    deprecated: parameter.deprecated,
                 }))
             }
+            ParameterType::Error {
+                transaction_type,
+                properties,
+            } => Some(Entity::Error(ErrorClass {
+                name: name.clone(),
+                description: parameter
+                    .description
+                    .clone()
+                    .map(|d| Comment::try_from(d).unwrap()),
+                transaction_type: transaction_type.clone(),
+                fields: Self::build_field_list(properties.iter()),
+            })),
             _ => None,
         }
     }
@@ -430,7 +594,7 @@ impl ResourceProcessor {
 
     fn update_type_references(entity: &mut Entity, name_mappings: &HashMap<String, String>) {
         let update_field = |field: &mut ObjectField| {
-            if let ScalarType::TypeReference(ref mut type_ref) = field.value_type.scalar
+            if let ScalarType::TypeReference(type_ref) = field.value_type.scalar.value_scalar_mut()
                 && let Some(new_name) = name_mappings.get(&type_ref.path)
             {
                 type_ref.name = Identifier::try_from(new_name.as_str()).unwrap();
@@ -448,10 +612,17 @@ impl ResourceProcessor {
                     update_field(field);
                 }
             }
+            Entity::Error(error) => {
+                for field in &mut error.fields {
+                    update_field(field);
+                }
+            }
             Entity::Union(union) => {
                 for variant in &mut union.variants {
-                    if let Some(new_name) = name_mappings.get(&variant.type_name.path) {
-                        variant.type_name.name = Identifier::try_from(new_name.as_str()).unwrap();
+                    if let UnionVariantType::TypeReference(type_name) = &mut variant.value_type
+                        && let Some(new_name) = name_mappings.get(&type_name.path)
+                    {
+                        type_name.name = Identifier::try_from(new_name.as_str()).unwrap();
                         variant.name = Identifier::try_from(new_name.as_str()).unwrap();
                     }
                 }
@@ -460,12 +631,9 @@ impl ResourceProcessor {
         }
     }
 
-    fn generate_directory(mut self, file_base_path: impl AsRef<Path>, _module_name: &str) {
-        let file_base_path = file_base_path.as_ref();
-
-        // Collect paths that are intersection constituents
-        let constituent_paths: std::collections::HashSet<String> = self
-            .entities
+    /// 인터섹션 구성 요소로만 쓰여 파일이 생성되지 않는 리소스의 경로 목록
+    fn constituent_paths(&self) -> std::collections::HashSet<String> {
+        self.entities
             .values()
             .filter_map(|entity| {
                 if let Entity::Intersection(intersection) = entity {
@@ -480,7 +648,12 @@ impl ResourceProcessor {
                 }
             })
             .flatten()
-            .collect();
+            .collect()
+    }
+
+    /// 이름이 중복되는 타입에 상위 디렉토리 이름을 접두사로 붙인 이름 목록 (path -> new_name)
+    fn build_name_mappings(&self) -> HashMap<String, String> {
+        let constituent_paths = self.constituent_paths();
 
         // Count type names to detect duplicates
         let mut type_name_counts: HashMap<String, usize> = HashMap::new();
@@ -503,6 +676,13 @@ impl ResourceProcessor {
                 name_mappings.insert(path.clone(), new_name);
             }
         }
+        name_mappings
+    }
+
+    fn generate_directory(mut self, file_base_path: impl AsRef<Path>, _module_name: &str) {
+        let file_base_path = file_base_path.as_ref();
+        let constituent_paths = self.constituent_paths();
+        let name_mappings = self.build_name_mappings();
 
         // Update all type references to use new names
         for entity in self.entities.values_mut() {
@@ -555,6 +735,14 @@ impl ResourceProcessor {
                     write!(content, "{intersection}").unwrap();
                     content
                 }
+                Entity::Error(error) => {
+                    use std::fmt::Write;
+                    let mut content = String::new();
+                    writeln!(&mut content, "import Foundation").unwrap();
+                    writeln!(content).unwrap();
+                    write!(content, "{error}").unwrap();
+                    content
+                }
             };
 
             let mut file_path =
@@ -567,21 +755,26 @@ impl ResourceProcessor {
     }
 }
 
-pub fn generate_resources_module(
-    resource: &Resource,
-    file_base_path: impl AsRef<Path>,
-    module_name: &str,
-) {
+fn process_resources(resource: &Resource) -> ResourceProcessor {
     let mut processor = ResourceProcessor {
         entities: HashMap::new(),
     };
     if let Resource::SubResources(subresources) = resource {
         for (key, value) in subresources.iter() {
-            if matches!(key.as_str(), "entity" | "request" | "response") {
+            if MOBILE_RESOURCE_ROOTS.contains(&key.as_str()) {
                 processor.process_resource(value, &mut vec![key.clone()]);
             }
         }
     }
+    processor
+}
+
+pub fn generate_resources_module(
+    resource: &Resource,
+    file_base_path: impl AsRef<Path>,
+    module_name: &str,
+) {
+    let mut processor = process_resources(resource);
     // Mobile-only transformations (iOS specific)
     for (path, entity) in processor.entities.iter_mut() {
         if path.starts_with("request/") {
@@ -607,9 +800,33 @@ pub fn generate_resources_module(
             }
         }
     }
+    let has_error = processor
+        .entities
+        .values()
+        .any(|entity| matches!(entity, Entity::Error(_)));
     processor.connect_union_parents();
     processor.generate_directory(&file_base_path, module_name);
 
+    // 모든 에러 타입이 따르는 기본 protocol 생성
+    if has_error {
+        let content = format!(
+            r#"import Foundation
+
+public protocol {BASE_ERROR_NAME}: Error {{
+    var transactionType: String? {{ get }}
+}}
+
+extension {BASE_ERROR_NAME} {{
+    public var transactionType: String? {{ nil }}
+}}
+"#
+        );
+        let exception_path = file_base_path.as_ref().join("exception");
+        std::fs::create_dir_all(&exception_path).unwrap();
+        let file_path = exception_path.join(format!("{BASE_ERROR_NAME}.swift"));
+        write_generated_file(file_path, content).unwrap();
+    }
+
     // JSONValue.swift 파일 생성
     let json_value_content = r#"import Foundation
 
@@ -656,7 +873,175 @@ public enum JSONValue: Codable, Equatable {
         }
     }
 }
+
+extension KeyedEncodingContainer {
+    /// 인코딩 결과가 빈 배열이나 빈 객체인 값은 생략합니다.
+    mutating func encodeIfNotEmpty<T: Encodable>(_ value: T?, forKey key: Key) throws {
+        guard let value = value else { return }
+        switch try JSONDecoder().decode(JSONValue.self, from: JSONEncoder().encode(value)) {
+        case .array(let array) where array.isEmpty: return
+        case .object(let object) where object.isEmpty: return
+        default: try encode(value, forKey: key)
+        }
+    }
+
+    /// `decimal` 타입은 정밀도 손실을 막기 위해 문자열로 인코딩합니다.
+    mutating func encode(_ value: Decimal, forKey key: Key) throws {
+        try encode(NSDecimalNumber(decimal: value).stringValue, forKey: key)
+    }
+
+    mutating func encodeIfPresent(_ value: Decimal?, forKey key: Key) throws {
+        guard let value = value else { return }
+        try encode(value, forKey: key)
+    }
+
+    mutating func encode(_ value: [Decimal], forKey key: Key) throws {
+        try encode(value.map { NSDecimalNumber(decimal: $0).stringValue }, forKey: key)
+    }
+
+    mutating func encodeIfPresent(_ value: [Decimal]?, forKey key: Key) throws {
+        guard let value = value else { return }
+        try encode(value, forKey: key)
+    }
+
+    mutating func encode(_ value: [String: Decimal], forKey key: Key) throws {
+        try encode(value.mapValues { NSDecimalNumber(decimal: $0).stringValue }, forKey: key)
+    }
+
+    mutating func encodeIfPresent(_ value: [String: Decimal]?, forKey key: Key) throws {
+        guard let value = value else { return }
+        try encode(value, forKey: key)
+    }
+}
+
+extension KeyedDecodingContainer {
+    func decode(_ type: Decimal.Type, forKey key: Key) throws -> Decimal {
+        let string = try decode(String.self, forKey: key)
+        guard let value = Decimal(string: string) else {
+            throw DecodingError.dataCorruptedError(forKey: key, in: self, debugDescription: "Invalid decimal \(string)")
+        }
+        return value
+    }
+
+    func decodeIfPresent(_ type: Decimal.Type, forKey key: Key) throws -> Decimal? {
+        guard contains(key), try !decodeNil(forKey: key) else { return nil }
+        return try decode(type, forKey: key)
+    }
+
+    func decode(_ type: [Decimal].Type, forKey key: Key) throws -> [Decimal] {
+        try decode([String].self, forKey: key).map { string in
+            guard let value = Decimal(string: string) else {
+                throw DecodingError.dataCorruptedError(forKey: key, in: self, debugDescription: "Invalid decimal \(string)")
+            }
+            return value
+        }
+    }
+
+    func decodeIfPresent(_ type: [Decimal].Type, forKey key: Key) throws -> [Decimal]? {
+        guard contains(key), try !decodeNil(forKey: key) else { return nil }
+        return try decode(type, forKey: key)
+    }
+
+    func decode(_ type: [String: Decimal].Type, forKey key: Key) throws -> [String: Decimal] {
+        try decode([String: String].self, forKey: key).mapValues { string in
+            guard let value = Decimal(string: string) else {
+                throw DecodingError.dataCorruptedError(forKey: key, in: self, debugDescription: "Invalid decimal \(string)")
+            }
+            return value
+        }
+    }
+
+    func decodeIfPresent(_ type: [String: Decimal].Type, forKey key: Key) throws -> [String: Decimal]? {
+        guard contains(key), try !decodeNil(forKey: key) else { return nil }
+        return try decode(type, forKey: key)
+    }
+}
 "#;
     let json_value_path = file_base_path.as_ref().join("JSONValue.swift");
     write_generated_file(json_value_path, json_value_content.to_string()).unwrap();
 }
+
+fn build_method(
+    name: &str,
+    method: &client_sdk_schema::Method,
+    name_mappings: &HashMap<String, String>,
+) -> Option<Method> {
+    if RESOURCE_INDEX
+        .with(|index| method.find_unsupported_mobile_parameter(index))
+        .is_some()
+    {
+        return None;
+    }
+
+    let build_type = |name: &str, parameter: &Parameter| {
+        let mut value_type = ResourceProcessor::build_field(name, parameter).value_type;
+        if let ScalarType::TypeReference(type_ref) = value_type.scalar.value_scalar_mut()
+            && let Some(new_name) = name_mappings.get(&type_ref.path)
+        {
+            type_ref.name = Identifier::try_from(new_name.as_str()).unwrap();
+        }
+        value_type
+    };
+    let callbacks = method
+        .callbacks
+        .iter()
+        .flatten()
+        .map(|(callback_name, callback)| Callback {
+            name: Identifier::try_from(callback_name.as_str()).unwrap(),
+            description: callback
+                .description
+                .clone()
+                .map(|d| Comment::try_from(d).unwrap()),
+            parameters: callback
+                .input
+                .iter()
+                .map(|(parameter_name, parameter)| build_type(parameter_name, parameter))
+                .collect(),
+            deprecated: callback.deprecated,
+            replacement: callback.replacement.clone(),
+        })
+        .collect();
+    Some(Method {
+        name: Identifier::try_from(name).unwrap(),
+        description: method
+            .description
+            .clone()
+            .map(|d| Comment::try_from(d).unwrap()),
+        input: build_type("request", &method.input),
+        output: method
+            .output
+            .as_ref()
+            .map(|output| build_type("response", output)),
+        callbacks,
+        deprecated: method.deprecated,
+        replacement: method.replacement.clone(),
+    })
+}
+
+/// 스키마의 메소드 목록으로 `PortOne` protocol을 생성합니다.
+///
+/// 모바일 SDK에서 생성되지 않는 타입을 사용하는 메소드는 제외되며, 스키마 검사에서 경고로 보고됩니다.
+pub fn generate_methods_module<'a>(
+    resource: &Resource,
+    methods: impl IntoIterator<Item = (&'a String, &'a client_sdk_schema::Method)>,
+    file_base_path: impl AsRef<Path>,
+) {
+    let name_mappings = process_resources(resource).build_name_mappings();
+    let facade = MethodFacade {
+        name: Identifier::try_from("PortOne").unwrap(),
+        methods: methods
+            .into_iter()
+            .filter_map(|(name, method)| build_method(name, method, &name_mappings))
+            .collect(),
+    };
+
+    use std::fmt::Write;
+    let mut content = String::new();
+    writeln!(&mut content, "import Foundation").unwrap();
+    writeln!(content).unwrap();
+    write!(content, "{facade}").unwrap();
+
+    let file_path = file_base_path.as_ref().join("PortOne.swift");
+    std::fs::create_dir_all(file_path.parent().unwrap()).unwrap();
+    write_generated_file(file_path, content).unwrap();
+}
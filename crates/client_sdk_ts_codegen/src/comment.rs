@@ -1,41 +1,112 @@
 use client_sdk_utils::{MdastNodeExt, ToMdastExt};
 
 pub(crate) trait JsDocExt<T: AsRef<str>> {
-    fn to_jsdoc(&self, deprecated: bool) -> String;
+    fn to_jsdoc(&self, deprecated: bool) -> String {
+        self.to_jsdoc_with_tags(deprecated, None, None)
+    }
+
+    /// `@deprecated` 태그에 대체 항목을 함께 표시합니다.
+    fn to_jsdoc_with_replacement(&self, deprecated: bool, replacement: Option<&str>) -> String {
+        self.to_jsdoc_with_tags(deprecated, replacement, None)
+    }
+
+    /// `@default` 태그로 기본값을 함께 표시합니다.
+    fn to_jsdoc_with_default(&self, deprecated: bool, default: Option<&str>) -> String {
+        self.to_jsdoc_with_tags(deprecated, None, default)
+    }
+
+    fn to_jsdoc_with_tags(
+        &self,
+        deprecated: bool,
+        replacement: Option<&str>,
+        default: Option<&str>,
+    ) -> String;
 }
 
 impl<T: AsRef<str>> JsDocExt<T> for T {
-    fn to_jsdoc(&self, deprecated: bool) -> String {
-        generate_jsdoc_comment(self.as_ref(), deprecated)
+    fn to_jsdoc_with_tags(
+        &self,
+        deprecated: bool,
+        replacement: Option<&str>,
+        default: Option<&str>,
+    ) -> String {
+        generate_jsdoc_comment(Some(self.as_ref()), deprecated, replacement, default)
     }
 }
 
 impl<T: AsRef<str>> JsDocExt<T> for Option<T> {
-    fn to_jsdoc(&self, deprecated: bool) -> String {
+    fn to_jsdoc_with_tags(
+        &self,
+        deprecated: bool,
+        replacement: Option<&str>,
+        default: Option<&str>,
+    ) -> String {
         match self {
-            Some(comment) => generate_jsdoc_comment(comment.as_ref(), deprecated),
+            Some(comment) => {
+                generate_jsdoc_comment(Some(comment.as_ref()), deprecated, replacement, default)
+            }
+            None if deprecated || default.is_some() => {
+                generate_jsdoc_comment(None, deprecated, replacement, default)
+            }
             None => String::new(),
         }
     }
 }
 
-pub fn generate_jsdoc_comment(comment: &str, deprecated: bool) -> String {
+pub fn generate_jsdoc_comment(
+    comment: Option<&str>,
+    deprecated: bool,
+    replacement: Option<&str>,
+    default: Option<&str>,
+) -> String {
     let mut comment = comment
-        .to_mdast()
-        .unwrap()
-        .remove_jsx_elements()
-        .to_markdown_string()
-        .unwrap()
-        .trim()
-        .split('\n')
-        .map(|line| format!("* {line}"))
-        .collect::<Vec<_>>();
+        .map(|comment| {
+            comment
+                .to_mdast()
+                .unwrap()
+                .remove_jsx_elements()
+                .to_markdown_string()
+                .unwrap()
+                .trim()
+                .split('\n')
+                .map(|line| format!("* {line}"))
+                .collect::<Vec<_>>()
+        })
+        .unwrap_or_default();
+
+    if let Some(default) = default {
+        comment.push(format!("* @default {}", escape_comment_end(default)));
+    }
 
     if deprecated {
-        comment.push("* @deprecated".to_string());
+        match replacement {
+            Some(replacement) => comment.push(format!(
+                "* @deprecated Use `{}` instead.",
+                escape_comment_end(replacement)
+            )),
+            None => comment.push("* @deprecated".to_string()),
+        }
     }
 
     let comment = comment.join("\n");
 
     format!("\n/**\n{comment}\n*/\n")
 }
+
+/// 스키마의 값이 주석을 일찍 닫지 않도록 `*/`를 이스케이프합니다.
+fn escape_comment_end(value: &str) -> String {
+    value.replace("*/", "*\\/")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn escapes_comment_end_in_tags() {
+        assert_eq!(
+            generate_jsdoc_comment(None, true, Some("a */ b"), Some("\"*/\"")),
+            "\n/**\n* @default \"*\\/\"\n* @deprecated Use `a *\\/ b` instead.\n*/\n"
+        );
+    }
+}
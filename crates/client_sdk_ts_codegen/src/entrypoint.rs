@@ -9,7 +9,11 @@ use client_sdk_schema::{self as schema};
 use crate::print;
 use client_sdk_utils::write_generated_file;
 
-pub fn generate_entrypoint_module(path: &PathBuf, methods: &IndexMap<String, schema::Method>) {
+pub fn generate_entrypoint_module(
+    path: &PathBuf,
+    methods: &IndexMap<String, schema::Method>,
+    validators: bool,
+) {
     let current_module_path = path.join("index.ts");
     let imports = methods.keys().fold(String::new(), |mut acc, method_name| {
         acc.push_str(
@@ -36,6 +40,11 @@ pub fn generate_entrypoint_module(path: &PathBuf, methods: &IndexMap<String, sch
         acc.push_str(&ts_parse!("export * from './{method_name}.js';" as JsExport).to_string());
         acc
     });
+    let validation_exports = if validators {
+        "export type { ValidationError } from './validation.js';"
+    } else {
+        ""
+    };
     let index_ts = fs::read_to_string(&current_module_path).unwrap();
     let module = ts_parse!(
         r#"
@@ -44,6 +53,7 @@ pub fn generate_entrypoint_module(path: &PathBuf, methods: &IndexMap<String, sch
         {portone_object_decl}
         
         export {{ setPortOneJsSdkUrl as __INTERNAL__setPortOneSdkUrl }} from './loader.js'
+        {validation_exports}
 
         {method_exports}
 
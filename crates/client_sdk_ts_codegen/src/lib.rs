@@ -6,6 +6,7 @@ pub mod macros;
 pub mod method;
 pub mod parameter;
 pub mod print;
+pub mod validator;
 
 use std::{fs, path::PathBuf};
 
@@ -19,12 +20,14 @@ use client_sdk_utils::write_generated_file;
 use import::{ImportEntry, generate_import_statements};
 use indexmap::IndexSet;
 use parameter::generate_named_parameter;
+use validator::generate_validator_function;
 
 pub fn generate_resource_module(
     path: &PathBuf,
     resource_name: &str,
     resource: &schema::Resource,
     resource_base_path: &PathBuf, // 리소스의 기본 경로
+    validators: bool,             // `validate{Name}` 함수 생성 여부
 ) {
     match resource {
         schema::Resource::SubResources(index_map) => {
@@ -35,7 +38,13 @@ pub fn generate_resource_module(
             let mut subdirectories = Vec::new();
 
             for (name, resource) in index_map {
-                generate_resource_module(&current_path, name, resource, resource_base_path);
+                generate_resource_module(
+                    &current_path,
+                    name,
+                    resource,
+                    resource_base_path,
+                    validators,
+                );
 
                 match resource {
                     schema::Resource::SubResources(_) => {
@@ -92,6 +101,12 @@ pub fn generate_resource_module(
                 &current_module_path,
                 resource_base_path,
             );
+            if validators
+                && let Some(validator) =
+                    generate_validator_function(parameter, &mut imports, resource_base_path)
+            {
+                decls.push(validator.into());
+            }
 
             let import_statements = generate_import_statements(&imports, &current_module_path);
             let imports = import_statements
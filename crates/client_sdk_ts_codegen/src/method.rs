@@ -5,19 +5,24 @@ use std::path::PathBuf;
 use biome_js_factory::make::{self};
 use biome_js_syntax::{AnyJsDeclaration, AnyJsModuleItem};
 use biome_rowan::AstNode;
-use client_sdk_schema::{self as schema};
+use client_sdk_schema::{self as schema, DeprecationExt};
 use client_sdk_ts_codegen_macros::ts_parse;
 use indexmap::{IndexMap, IndexSet};
 
 use crate::comment::JsDocExt;
 use crate::import::{ImportEntry, generate_import_statements};
 use crate::parameter::generate_parameter;
+use crate::validator::{generate_validator, import_validation};
 use crate::{js_export, print};
 use client_sdk_utils::write_generated_file;
 
-pub fn generate_method_modules(path: &PathBuf, methods: &IndexMap<String, schema::Method>) {
+pub fn generate_method_modules(
+    path: &PathBuf,
+    methods: &IndexMap<String, schema::Method>,
+    validators: bool,
+) {
     for (method_name, method) in methods {
-        generate_method_module(path, method_name, method, path);
+        generate_method_module(path, method_name, method, path, validators);
     }
 }
 
@@ -26,6 +31,7 @@ pub fn generate_method_module(
     method_name: &str,
     method: &schema::Method,
     resource_base_path: &PathBuf, // 리소스의 기본 경로
+    validators: bool,             // 개발 모드에서 요청 값 검사 여부
 ) {
     let current_module_path = path.join(format!("{method_name}.ts"));
 
@@ -67,7 +73,17 @@ pub fn generate_method_module(
         None => "void".to_string(),
     };
 
-    let description = method.description.to_jsdoc(false);
+    let validation = if validators {
+        import_validation(&mut imports, "validateInDevMode", false, resource_base_path);
+        let validator = generate_validator(&method.input, &mut imports, "", resource_base_path);
+        format!("validateInDevMode('{method_name}', {validator}, request)")
+    } else {
+        String::new()
+    };
+
+    let description = method
+        .description
+        .to_jsdoc_with_replacement(method.deprecated(), method.replacement());
     let func = match callbacks {
         Some(callbacks) => ts_parse!(
             r#"
@@ -75,6 +91,7 @@ pub fn generate_method_module(
                 request: {input},
                 callbacks: {{{callbacks}}},
             ): Promise<{output}> {{
+                {validation}
                 return loadScript().then((sdk) =>
                     sdk.{method_name}(request, callbacks)
                 )
@@ -86,6 +103,7 @@ pub fn generate_method_module(
             {description}function {method_name}(
                 request: {input},
             ): Promise<{output}> {{
+                {validation}
                 return loadScript().then((sdk) =>
                     sdk.{method_name}(request)
                 )
@@ -129,7 +147,10 @@ pub(crate) fn generate_callbacks(
                 callbacks
                     .iter()
                     .fold(String::new(), |mut output, (callback_name, callback)| {
-                        let description = callback.description.to_jsdoc(false);
+                        let description = callback.description.to_jsdoc_with_replacement(
+                            callback.deprecated(),
+                            callback.replacement(),
+                        );
                         let callback = callback.input.iter().fold(
                             String::new(),
                             |mut acc, (parameter_name, parameter)| {
@@ -2,7 +2,7 @@ use std::fmt::Write;
 use std::path::PathBuf;
 
 use biome_js_syntax::{AnyJsDeclaration, JsVariableDeclaration};
-use client_sdk_schema::{self as schema, ParameterExt, RESOURCE_INDEX};
+use client_sdk_schema::{self as schema, DeprecationExt, ParameterExt, RESOURCE_INDEX};
 use client_sdk_ts_codegen_macros::ts_parse;
 use convert_case::Casing;
 use indexmap::{IndexMap, IndexSet};
@@ -57,7 +57,9 @@ pub(crate) fn generate_named_parameter(
         current_module_path,
         resource_base_path,
     );
-    let description = parameter.description().to_jsdoc(parameter.deprecated());
+    let default = parameter.default().map(ToString::to_string);
+    let description = parameter_description(parameter)
+        .to_jsdoc_with_default(parameter.deprecated(), default.as_deref());
 
     match parameter.r#type() {
         schema::ParameterType::Error { .. } => (),
@@ -121,9 +123,12 @@ fn generate_parameter_type(
     resource_base_path: &PathBuf,  // 리소스의 기본 경로 (예: 프로젝트 루트)
 ) -> String {
     match parameter_type {
-        schema::ParameterType::String => String::from("string"),
+        schema::ParameterType::String { .. } => String::from("string"),
         schema::ParameterType::StringLiteral { value } => format!("'{value}'"),
         schema::ParameterType::Integer => String::from("number"),
+        schema::ParameterType::Number { .. } => String::from("number"),
+        // 정밀도 손실을 막기 위해 문자열로 전달
+        schema::ParameterType::Decimal { .. } => String::from("string"),
         schema::ParameterType::Boolean => String::from("boolean"),
         schema::ParameterType::Array {
             items,
@@ -139,6 +144,17 @@ fn generate_parameter_type(
             );
             format!("{item_type}[]")
         }
+        schema::ParameterType::Map { values } => {
+            let value_type = generate_parameter(
+                values,
+                decls,
+                imports,
+                &format!("{parent_name}Value"),
+                current_module_path,
+                resource_base_path,
+            );
+            format!("Record<string, {value_type}>")
+        }
         schema::ParameterType::Object {
             properties,
             hide_if_empty: _,
@@ -387,8 +403,15 @@ fn generate_parameter_type_property(
         current_module_path,
         resource_base_path,
     );
-    let description = parameter.description().to_jsdoc(parameter.deprecated());
-    let optional_marker = if parameter.optional() { "?" } else { "" };
+    let default = parameter.default().map(ToString::to_string);
+    let description = parameter_description(parameter)
+        .to_jsdoc_with_default(parameter.deprecated(), default.as_deref());
+    // 비어있을 때 숨겨지는 프로퍼티는 생략 가능
+    let optional_marker = if parameter.optional() || parameter.hide_if_empty() {
+        "?"
+    } else {
+        ""
+    };
     let property_name = if property_name.contains('-') {
         format!("'{property_name}'")
     } else {
@@ -397,6 +420,21 @@ fn generate_parameter_type_property(
     format!("{description}{property_name}{optional_marker}: {member_type}")
 }
 
+/// 설명 뒤에 값의 제약 조건 목록을 덧붙입니다.
+fn parameter_description(parameter: &schema::Parameter) -> Option<String> {
+    let constraints = parameter
+        .r#type()
+        .constraints()
+        .iter()
+        .map(|constraint| format!("- {constraint}"))
+        .collect::<Vec<_>>();
+    match (parameter.description(), constraints.is_empty()) {
+        (description, true) => description,
+        (None, false) => Some(constraints.join("\n")),
+        (Some(description), false) => Some(format!("{description}\n\n{}", constraints.join("\n"))),
+    }
+}
+
 fn generate_const_enum_declaration(
     name: &str,
     description: Option<&str>,
@@ -407,7 +445,9 @@ fn generate_const_enum_declaration(
             variants
                 .iter()
                 .fold(String::new(), |mut output, (variant_name, variant)| {
-                    let description = variant.description.to_jsdoc(false);
+                    let description = variant
+                        .description
+                        .to_jsdoc_with_replacement(variant.deprecated(), variant.replacement());
                     let identifier = variant.alias.as_deref().unwrap_or(variant_name);
 
                     writeln!(output, "{description}'{identifier}': '{variant_name}',").unwrap();
@@ -482,7 +522,7 @@ mod tests {
         let unnamed_param = schema::Parameter::new(
             None,
             Some("Optional user name".to_string()),
-            schema::ParameterType::String,
+            schema::ParameterType::string(),
             true,
             None,
             false,
@@ -548,7 +588,7 @@ mod tests {
             schema::Parameter::new(
                 Some("Name".to_string()),
                 Some("User name".to_string()),
-                schema::ParameterType::String,
+                schema::ParameterType::string(),
                 false,
                 None,
                 false,
@@ -628,6 +668,11 @@ mod tests {
             schema::EnumVariant {
                 description: Some("Administrator".to_string()),
                 alias: None,
+                flag_options: None,
+                deprecated: false,
+                replacement: None,
+                since: None,
+                removed_in: None,
             },
         );
         variants.insert(
@@ -635,6 +680,11 @@ mod tests {
             schema::EnumVariant {
                 description: Some("Regular user".to_string()),
                 alias: None,
+                flag_options: None,
+                deprecated: false,
+                replacement: None,
+                since: None,
+                removed_in: None,
             },
         );
         variants.insert(
@@ -642,6 +692,11 @@ mod tests {
             schema::EnumVariant {
                 description: Some("Guest user".to_string()),
                 alias: None,
+                flag_options: None,
+                deprecated: false,
+                replacement: None,
+                since: None,
+                removed_in: None,
             },
         );
 
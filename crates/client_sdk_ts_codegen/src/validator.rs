@@ -0,0 +1,404 @@
+use std::fs;
+use std::path::PathBuf;
+
+use biome_js_syntax::JsFunctionDeclaration;
+use client_sdk_schema::{self as schema, ParameterExt, RESOURCE_INDEX};
+use client_sdk_ts_codegen_macros::ts_parse;
+use convert_case::Casing;
+use indexmap::IndexSet;
+
+use crate::import::{ImportEntry, resource_ref_to_path};
+use crate::print;
+use client_sdk_utils::write_generated_file;
+
+/// 검사 함수의 기본 구현이 담긴 `validation.ts` 모듈을 생성합니다.
+pub fn generate_validation_module(path: &PathBuf) {
+    let validation = include_str!("../templates/validation.ts");
+    let module = ts_parse!("{validation}" as JsModule);
+    let module = print::print_node(&module.into());
+    fs::create_dir_all(path).unwrap();
+    write_generated_file(path.join("validation.ts"), module).unwrap();
+}
+
+/// 이름이 있는 파라미터의 값을 검사하는 `validate{Name}` 함수를 생성합니다.
+///
+/// 에러 타입은 SDK가 반환하는 값이므로 검사 함수를 생성하지 않습니다.
+pub(crate) fn generate_validator_function(
+    parameter: &schema::Parameter,
+    imports: &mut IndexSet<ImportEntry>,
+    resource_base_path: &PathBuf,
+) -> Option<JsFunctionDeclaration> {
+    if let schema::ParameterType::Error { .. } = parameter.r#type() {
+        return None;
+    }
+    let type_name = parameter.name().unwrap_or_default();
+    let validator = generate_validator(parameter, imports, type_name, resource_base_path);
+    import_validation(imports, "ValidationError", true, resource_base_path);
+    Some(ts_parse!(
+        r#"
+        function validate{type_name}(value: unknown, path = ''): ValidationError[] {{
+            return {validator}(value, path)
+        }}"# as JsFunctionDeclaration
+    ))
+}
+
+/// `validation.ts`의 검사 함수를 조합하여 파라미터 값을 검사하는 식을 만듭니다.
+///
+/// 인라인 enum은 `generate_parameter`와 같은 규칙으로 이름 붙여진 상수를 사용합니다.
+pub(crate) fn generate_validator(
+    parameter: &schema::Parameter,
+    imports: &mut IndexSet<ImportEntry>,
+    parent_name: &str,
+    resource_base_path: &PathBuf,
+) -> String {
+    let parent_name = parameter.name().unwrap_or(parent_name);
+    let validator =
+        generate_type_validator(parameter.r#type(), imports, parent_name, resource_base_path);
+    if parameter.optional() {
+        import_validation(imports, "optionalValidator", false, resource_base_path);
+        format!("optionalValidator({validator})")
+    } else {
+        validator
+    }
+}
+
+fn generate_type_validator(
+    parameter_type: &schema::ParameterType,
+    imports: &mut IndexSet<ImportEntry>,
+    parent_name: &str,
+    resource_base_path: &PathBuf,
+) -> String {
+    let mut import = |name: &str| import_validation(imports, name, false, resource_base_path);
+    match parameter_type {
+        schema::ParameterType::String {
+            pattern,
+            min_length,
+            max_length,
+            format,
+        } => {
+            import("stringValidator");
+            let mut constraints = vec![];
+            if let Some(pattern) = pattern {
+                constraints.push(format!("pattern: {}", js_string_literal(pattern)));
+            }
+            if let Some(min_length) = min_length {
+                constraints.push(format!("minLength: {min_length}"));
+            }
+            if let Some(max_length) = max_length {
+                constraints.push(format!("maxLength: {max_length}"));
+            }
+            if let Some(format) = format {
+                constraints.push(format!("format: '{format}'"));
+            }
+            if constraints.is_empty() {
+                String::from("stringValidator({})")
+            } else {
+                format!("stringValidator({{ {} }})", constraints.join(", "))
+            }
+        }
+        schema::ParameterType::Integer => {
+            import("integerValidator");
+            String::from("integerValidator")
+        }
+        schema::ParameterType::Boolean => {
+            import("booleanValidator");
+            String::from("booleanValidator")
+        }
+        schema::ParameterType::EmptyObject => {
+            import("objectValidator");
+            String::from("objectValidator({})")
+        }
+        // `json`은 모든 값을 허용하고, 에러는 SDK가 반환하는 값이므로 검사하지 않음
+        schema::ParameterType::Json | schema::ParameterType::Error { .. } => {
+            import("anyValidator");
+            String::from("anyValidator")
+        }
+        schema::ParameterType::Number { minimum, maximum } => {
+            import("numberValidator");
+            format!("numberValidator({})", range_arguments(*minimum, *maximum))
+        }
+        schema::ParameterType::Decimal { minimum, maximum } => {
+            import("decimalValidator");
+            format!("decimalValidator({})", range_arguments(*minimum, *maximum))
+        }
+        schema::ParameterType::StringLiteral { value } => {
+            import("literalValidator");
+            format!("literalValidator({})", js_string_literal(value))
+        }
+        schema::ParameterType::Enum { value_prefix, .. } => {
+            import("enumValidator");
+            match value_prefix {
+                Some(value_prefix) => format!(
+                    "enumValidator(Object.values({parent_name}), {})",
+                    js_string_literal(value_prefix)
+                ),
+                None => format!("enumValidator(Object.values({parent_name}))"),
+            }
+        }
+        schema::ParameterType::Array { items, .. } => {
+            import("arrayValidator");
+            let items = generate_validator(
+                items,
+                imports,
+                &format!("{parent_name}Item"),
+                resource_base_path,
+            );
+            format!("arrayValidator({items})")
+        }
+        schema::ParameterType::Map { values } => {
+            import("recordValidator");
+            let values = generate_validator(
+                values,
+                imports,
+                &format!("{parent_name}Value"),
+                resource_base_path,
+            );
+            format!("recordValidator({values})")
+        }
+        schema::ParameterType::Object { properties, .. } => {
+            import("objectValidator");
+            let properties = properties
+                .iter()
+                .map(|(property_name, parameter)| {
+                    // 비어있을 때 숨겨지는 프로퍼티는 생략 가능
+                    let required = !(parameter.optional() || parameter.hide_if_empty());
+                    let validator = generate_validator(
+                        parameter,
+                        imports,
+                        &property_parent_name(parent_name, property_name),
+                        resource_base_path,
+                    );
+                    format!(
+                        "{}: [{required}, {validator}],",
+                        js_string_literal(property_name)
+                    )
+                })
+                .collect::<String>();
+            format!("objectValidator({{{properties}}})")
+        }
+        schema::ParameterType::OneOf { properties, .. } => {
+            import("oneOfValidator");
+            let properties = properties
+                .iter()
+                .map(|(property_name, parameter)| {
+                    let validator = generate_validator(
+                        parameter,
+                        imports,
+                        &property_parent_name(parent_name, property_name),
+                        resource_base_path,
+                    );
+                    format!("{}: {validator},", js_string_literal(property_name))
+                })
+                .collect::<String>();
+            format!("oneOfValidator({{{properties}}})")
+        }
+        schema::ParameterType::Union { types, .. } => {
+            import("unionValidator");
+            let types = generate_member_validators(
+                types,
+                imports,
+                &format!("{parent_name}Union"),
+                resource_base_path,
+            );
+            format!("unionValidator([{types}])")
+        }
+        schema::ParameterType::Intersection { types, .. } => {
+            import("intersectionValidator");
+            let types = generate_member_validators(
+                types,
+                imports,
+                &format!("{parent_name}Intersection"),
+                resource_base_path,
+            );
+            format!("intersectionValidator([{types}])")
+        }
+        schema::ParameterType::ResourceRef(resource) => {
+            let resource_ref = resource.resource_ref();
+            let referenced = RESOURCE_INDEX.with(|resource_index| {
+                resource_index.get(resource_ref).map(|parameter| {
+                    let is_error =
+                        matches!(parameter.r#type(), schema::ParameterType::Error { .. });
+                    (parameter.name().map(str::to_string), is_error)
+                })
+            });
+            let type_name = match referenced {
+                Some((_, true)) => {
+                    import("anyValidator");
+                    return String::from("anyValidator");
+                }
+                Some((Some(name), false)) => name,
+                _ => resource_ref.split('/').next_back().unwrap().to_string(),
+            };
+            let validator_name = format!("validate{type_name}");
+            imports.insert(ImportEntry {
+                type_name: validator_name.clone(),
+                path: resource_ref_to_path(resource_ref, resource_base_path),
+                is_type_only: false,
+                alias: None,
+            });
+            validator_name
+        }
+    }
+}
+
+fn generate_member_validators(
+    types: &[schema::Parameter],
+    imports: &mut IndexSet<ImportEntry>,
+    parent_name: &str,
+    resource_base_path: &PathBuf,
+) -> String {
+    types
+        .iter()
+        .enumerate()
+        .map(|(i, parameter)| {
+            generate_validator(
+                parameter,
+                imports,
+                &format!("{parent_name}{i}"),
+                resource_base_path,
+            )
+        })
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+fn range_arguments(minimum: Option<f64>, maximum: Option<f64>) -> String {
+    let bound = |bound: Option<f64>| bound.map_or(String::from("undefined"), |b| b.to_string());
+    format!("{}, {}", bound(minimum), bound(maximum))
+}
+
+/// 값을 작은따옴표 문자열 리터럴로 만듭니다.
+fn js_string_literal(value: &str) -> String {
+    let escaped = value
+        .replace('\\', "\\\\")
+        .replace('\'', "\\'")
+        .replace('\n', "\\n");
+    format!("'{escaped}'")
+}
+
+fn property_parent_name(parent_name: &str, property_name: &str) -> String {
+    format!(
+        "{parent_name}{property_name}",
+        property_name = property_name.to_case(convert_case::Case::Pascal)
+    )
+}
+
+pub(crate) fn import_validation(
+    imports: &mut IndexSet<ImportEntry>,
+    name: &str,
+    is_type_only: bool,
+    resource_base_path: &PathBuf,
+) {
+    imports.insert(ImportEntry {
+        type_name: name.to_string(),
+        path: resource_base_path.join("validation.ts"),
+        is_type_only,
+        alias: None,
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use indexmap::IndexMap;
+    use pretty_assertions::assert_eq;
+
+    fn parameter(r#type: schema::ParameterType, optional: bool) -> schema::Parameter {
+        schema::Parameter::new(None, None, r#type, optional, None, false)
+    }
+
+    #[test]
+    fn test_generate_validator_object() {
+        let bank = schema::ParameterType::Enum {
+            variants: IndexMap::new(),
+            value_prefix: Some("BANK".to_string()),
+        };
+        let method = schema::ParameterType::OneOf {
+            properties: IndexMap::from([
+                (
+                    "card".to_string(),
+                    parameter(schema::ParameterType::string(), false),
+                ),
+                ("bank".to_string(), parameter(bank, false)),
+            ]),
+            hide_if_empty: false,
+        };
+        let request = schema::ParameterType::Object {
+            properties: IndexMap::from([
+                (
+                    "kind".to_string(),
+                    parameter(
+                        schema::ParameterType::StringLiteral {
+                            value: "it's".to_string(),
+                        },
+                        false,
+                    ),
+                ),
+                (
+                    "amount".to_string(),
+                    parameter(schema::ParameterType::Integer, false),
+                ),
+                (
+                    "isTest".to_string(),
+                    parameter(schema::ParameterType::Boolean, true),
+                ),
+                ("method".to_string(), parameter(method, false)),
+                (
+                    "memo".to_string(),
+                    parameter(schema::ParameterType::string(), true),
+                ),
+                (
+                    "code".to_string(),
+                    parameter(
+                        schema::ParameterType::String {
+                            pattern: Some(r"^\d+$".to_string()),
+                            min_length: None,
+                            max_length: Some(10),
+                            format: None,
+                        },
+                        false,
+                    ),
+                ),
+            ]),
+            hide_if_empty: false,
+        };
+
+        let mut imports = IndexSet::new();
+        let validator = generate_validator(
+            &parameter(request, false),
+            &mut imports,
+            "Request",
+            &PathBuf::new(),
+        );
+        assert_eq!(
+            validator,
+            "objectValidator({\
+            'kind': [true, literalValidator('it\\'s')],\
+            'amount': [true, integerValidator],\
+            'isTest': [false, optionalValidator(booleanValidator)],\
+            'method': [true, oneOfValidator({\
+            'card': stringValidator({}),\
+            'bank': enumValidator(Object.values(RequestMethodBank), 'BANK'),\
+            })],\
+            'memo': [false, optionalValidator(stringValidator({}))],\
+            'code': [true, stringValidator({ pattern: '^\\\\d+$', maxLength: 10 })],\
+            })"
+        );
+        assert_eq!(
+            imports
+                .iter()
+                .map(|entry| entry.type_name.as_str())
+                .collect::<Vec<_>>(),
+            vec![
+                "objectValidator",
+                "literalValidator",
+                "integerValidator",
+                "booleanValidator",
+                "optionalValidator",
+                "oneOfValidator",
+                "stringValidator",
+                "enumValidator",
+            ]
+        );
+    }
+}
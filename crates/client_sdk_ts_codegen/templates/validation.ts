@@ -0,0 +1,196 @@
+export type ValidationError = {
+  /** 잘못된 값의 위치 (예: `request.customer.phoneNumber`) */
+  path: string;
+  message: string;
+};
+
+export type Validator = (value: unknown, path: string) => ValidationError[];
+
+function join(path: string, key: string | number): string {
+  if (typeof key === "number") return `${path}[${key}]`;
+  return path === "" ? key : `${path}.${key}`;
+}
+
+function isRecord(value: unknown): value is Record<string, unknown> {
+  return value != null && typeof value === "object" && !Array.isArray(value);
+}
+
+export const anyValidator: Validator = () => [];
+
+export const integerValidator: Validator = (value, path) =>
+  Number.isInteger(value) ? [] : [{ path, message: "expected an integer" }];
+
+export const booleanValidator: Validator = (value, path) =>
+  typeof value === "boolean" ? [] : [{ path, message: "expected a boolean" }];
+
+export function optionalValidator(validator: Validator): Validator {
+  return (value, path) => (value === undefined ? [] : validator(value, path));
+}
+
+export function literalValidator(expected: string): Validator {
+  return (value, path) =>
+    value === expected
+      ? []
+      : [{ path, message: `expected '${expected}', got ${JSON.stringify(value)}` }];
+}
+
+function checkRange(
+  value: number,
+  path: string,
+  minimum?: number,
+  maximum?: number
+): ValidationError[] {
+  if (minimum !== undefined && value < minimum) {
+    return [{ path, message: `must be greater than or equal to ${minimum}` }];
+  }
+  if (maximum !== undefined && value > maximum) {
+    return [{ path, message: `must be less than or equal to ${maximum}` }];
+  }
+  return [];
+}
+
+export function numberValidator(minimum?: number, maximum?: number): Validator {
+  return (value, path) => {
+    if (typeof value !== "number") return [{ path, message: "expected a number" }];
+    return checkRange(value, path, minimum, maximum);
+  };
+}
+
+export function decimalValidator(minimum?: number, maximum?: number): Validator {
+  return (value, path) => {
+    if (typeof value !== "string" || !/^-?\d+(?:\.\d+)?$/.test(value)) {
+      return [{ path, message: "expected a decimal string" }];
+    }
+    return checkRange(Number(value), path, minimum, maximum);
+  };
+}
+
+const stringFormats: Record<string, (value: string) => boolean> = {
+  email: (value) => /^[^\s@]+@[^\s@]+$/.test(value),
+  uri: (value) => {
+    try {
+      new URL(value);
+      return true;
+    } catch {
+      return false;
+    }
+  },
+  "date-time": (value) =>
+    /^\d{4}-\d{2}-\d{2}T\d{2}:\d{2}/.test(value) && !Number.isNaN(Date.parse(value)),
+  phone: (value) => /^\+?\d[\d-]*$/.test(value),
+};
+
+export function stringValidator(constraints: {
+  pattern?: string;
+  minLength?: number;
+  maxLength?: number;
+  format?: "email" | "uri" | "date-time" | "phone";
+}): Validator {
+  const { pattern, minLength, maxLength, format } = constraints;
+  const regExp = pattern === undefined ? undefined : new RegExp(pattern);
+  return (value, path) => {
+    if (typeof value !== "string") return [{ path, message: "expected a string" }];
+    if (minLength !== undefined && value.length < minLength) {
+      return [{ path, message: `must be at least ${minLength} characters long` }];
+    }
+    if (maxLength !== undefined && value.length > maxLength) {
+      return [{ path, message: `must be at most ${maxLength} characters long` }];
+    }
+    if (regExp !== undefined && !regExp.test(value)) {
+      return [{ path, message: `must match pattern ${pattern}` }];
+    }
+    if (format !== undefined && !stringFormats[format](value)) {
+      return [{ path, message: `expected a valid ${format}` }];
+    }
+    return [];
+  };
+}
+
+export function enumValidator(
+  values: readonly string[],
+  valuePrefix?: string
+): Validator {
+  return (value, path) => {
+    const prefix = `${valuePrefix}_`;
+    const unprefixed =
+      valuePrefix != null && typeof value === "string" && value.startsWith(prefix)
+        ? value.slice(prefix.length)
+        : value;
+    return typeof unprefixed === "string" && values.includes(unprefixed)
+      ? []
+      : [{ path, message: `unknown value ${JSON.stringify(value)}` }];
+  };
+}
+
+export function arrayValidator(items: Validator): Validator {
+  return (value, path) => {
+    if (!Array.isArray(value)) return [{ path, message: "expected an array" }];
+    return value.flatMap((item, i) => items(item, join(path, i)));
+  };
+}
+
+export function recordValidator(values: Validator): Validator {
+  return (value, path) => {
+    if (!isRecord(value)) return [{ path, message: "expected an object" }];
+    return Object.entries(value).flatMap(([key, item]) => values(item, join(path, key)));
+  };
+}
+
+export function objectValidator(
+  properties: Record<string, [required: boolean, validator: Validator]>
+): Validator {
+  return (value, path) => {
+    if (!isRecord(value)) return [{ path, message: "expected an object" }];
+    return Object.entries(properties).flatMap(([key, [required, validator]]) => {
+      if (value[key] === undefined) {
+        return required
+          ? [{ path: join(path, key), message: "required field is missing" }]
+          : [];
+      }
+      return validator(value[key], join(path, key));
+    });
+  };
+}
+
+export function oneOfValidator(properties: Record<string, Validator>): Validator {
+  return (value, path) => {
+    if (!isRecord(value)) return [{ path, message: "expected an object" }];
+    const present = Object.keys(properties).filter(
+      (key) => value[key] !== undefined
+    );
+    if (present.length !== 1) {
+      return [
+        {
+          path,
+          message: `expected exactly one of ${Object.keys(properties).join(", ")}, got ${present.length}`,
+        },
+      ];
+    }
+    const [key] = present;
+    return properties[key](value[key], join(path, key));
+  };
+}
+
+export function unionValidator(types: Validator[]): Validator {
+  return (value, path) =>
+    types.some((validator) => validator(value, path).length === 0)
+      ? []
+      : [{ path, message: "value does not match any of the allowed types" }];
+}
+
+export function intersectionValidator(types: Validator[]): Validator {
+  return (value, path) => types.flatMap((validator) => validator(value, path));
+}
+
+/** 개발 모드에서 요청 값을 검사하고 잘못된 값을 경고로 출력합니다. */
+export function validateInDevMode(
+  methodName: string,
+  validator: Validator,
+  value: unknown
+) {
+  // 번들러가 `process`를 정의하지 않는 브라우저 환경에서도 동작하도록 확인
+  if (typeof process !== "undefined" && process.env?.NODE_ENV === "production") return;
+  for (const error of validator(value, "request")) {
+    console.warn(`[PortOne] ${methodName}: ${error.path}: ${error.message}`);
+  }
+}